@@ -90,6 +90,11 @@ pub struct CompileCommand {
     /// apart from file names and line numbers.
     #[arg(long = "timings", value_name = "OUTPUT_JSON")]
     pub timings: Option<Option<PathBuf>>,
+
+    /// Discards all cached results after each compilation in watch mode, so
+    /// that every recompilation starts from scratch (useful for debugging)
+    #[arg(long = "no-cache")]
+    pub no_cache: bool,
 }
 
 /// Initializes a new project from a template
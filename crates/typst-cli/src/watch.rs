@@ -43,6 +43,9 @@ pub fn watch(mut timer: Timer, mut command: CompileCommand) -> StrResult<()> {
 
     // Perform initial compilation.
     timer.record(&mut world, |world| compile_once(world, &mut command, true))??;
+    if command.no_cache {
+        comemo::evict(0);
+    }
 
     // Watch all dependencies of the initial compilation.
     watcher.update(world.dependencies())?;
@@ -58,8 +61,9 @@ pub fn watch(mut timer: Timer, mut command: CompileCommand) -> StrResult<()> {
         // Recompile.
         timer.record(&mut world, |world| compile_once(world, &mut command, true))??;
 
-        // Evict the cache.
-        comemo::evict(10);
+        // Evict the cache. Without caching, evict everything so that the
+        // next compilation starts from scratch.
+        comemo::evict(if command.no_cache { 0 } else { 10 });
 
         // Adjust the file watching.
         watcher.update(world.dependencies())?;
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use ecow::EcoVec;
 
@@ -15,6 +16,7 @@ pub struct Tracer {
     warnings_set: HashSet<u128>,
     delayed: EcoVec<SourceDiagnostic>,
     values: EcoVec<(Value, Option<Styles>)>,
+    progress: Option<Arc<dyn Fn(usize) + Send + Sync>>,
 }
 
 impl Tracer {
@@ -46,6 +48,15 @@ impl Tracer {
     pub fn values(self) -> EcoVec<(Value, Option<Styles>)> {
         self.values
     }
+
+    /// Register a callback that is invoked during layout with the number of
+    /// pages laid out so far.
+    ///
+    /// The count starts over with each layout iteration, so it can go down
+    /// when a document needs multiple iterations to converge.
+    pub fn on_progress(&mut self, callback: impl Fn(usize) + Send + Sync + 'static) {
+        self.progress = Some(Arc::new(callback));
+    }
 }
 
 #[comemo::track]
@@ -73,6 +84,13 @@ impl Tracer {
         }
     }
 
+    /// Report that the given number of pages has been laid out so far.
+    pub fn progress(&mut self, pages: usize) {
+        if let Some(callback) = &self.progress {
+            callback(pages);
+        }
+    }
+
     /// Trace a value for the span.
     pub fn value(&mut self, value: Value, styles: Option<Styles>) {
         if self.values.len() < Self::MAX_VALUES {
@@ -80,3 +98,31 @@ impl Tracer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn test_progress_callback() {
+        let reported = Arc::new(Mutex::new(vec![]));
+        let mut tracer = Tracer::new();
+        tracer.on_progress({
+            let reported = reported.clone();
+            move |pages| reported.lock().unwrap().push(pages)
+        });
+
+        for pages in 1..=3 {
+            tracer.progress(pages);
+        }
+
+        // Clones of the tracer share the callback.
+        tracer.clone().progress(4);
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(*reported, [1, 2, 3, 4]);
+        assert!(reported.windows(2).all(|w| w[0] < w[1]));
+    }
+}
@@ -19,7 +19,9 @@ use crate::foundations::{
     Value,
 };
 use crate::introspection::{Location, Meta, MetaElem};
-use crate::layout::{AlignElem, Alignment, Axes, Length, MoveElem, PadElem, Rel, Sides};
+use crate::layout::{
+    AlignElem, Alignment, Axes, Length, MoveElem, PadElem, Rel, Sides, Spacing,
+};
 use crate::model::{Destination, EmphElem, StrongElem};
 use crate::realize::{Behave, Behaviour};
 use crate::syntax::Span;
@@ -492,9 +494,9 @@ impl Content {
     /// Pad this content at the sides.
     pub fn padded(self, padding: Sides<Rel<Length>>) -> Self {
         PadElem::new(self)
-            .with_left(padding.left)
+            .with_left(Spacing::Rel(padding.left))
             .with_top(padding.top)
-            .with_right(padding.right)
+            .with_right(Spacing::Rel(padding.right))
             .with_bottom(padding.bottom)
             .pack()
     }
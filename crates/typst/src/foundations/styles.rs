@@ -137,6 +137,16 @@ impl Styles {
         })
     }
 
+    /// Whether this list contains a property for the given field of the given
+    /// element.
+    pub fn has<T: NativeElement>(&self, id: u8) -> bool {
+        let elem = T::elem();
+        self.0
+            .iter()
+            .filter_map(|entry| entry.property())
+            .any(|property| property.is(elem, id))
+    }
+
     /// Set a font family composed of a preferred family and existing families
     /// from a style chain.
     pub fn set_family(&mut self, preferred: FontFamily, existing: StyleChain) {
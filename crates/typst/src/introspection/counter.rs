@@ -130,6 +130,30 @@ use crate::World;
 /// Arabic numbers.
 /// ```
 ///
+/// The total number of pages is provided by the counter's
+/// [`final`]($counter.final) value. You can use it to build custom footers
+/// such as "Page 3 of 12".
+///
+/// ```example
+/// >>> #set page(
+/// >>>   height: 100pt,
+/// >>>   margin: (bottom: 24pt, rest: 16pt),
+/// >>> )
+/// #set page(footer: context [
+///   Page #counter(page).display()
+///   of #counter(page).final().first()
+/// ])
+///
+/// #lorem(30)
+/// ```
+///
+/// Like all introspection, the final page count is only known once the
+/// document has been laid out. Typst thus lays the document out repeatedly,
+/// resolving each query with the results of the previous attempt, until
+/// nothing changes anymore. If the page count keeps changing, e.g. because
+/// the displayed count itself makes the document one page longer, Typst
+/// gives up after five attempts and emits a warning.
+///
 /// # Custom counters
 /// To define your own counter, call the `counter` function with a string as a
 /// key. This key identifies the counter globally.
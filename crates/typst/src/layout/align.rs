@@ -5,10 +5,11 @@ use ecow::{eco_format, EcoString};
 use crate::diag::{bail, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, func, scope, ty, CastInfo, Content, Fold, FromValue, IntoValue, Packed,
-    Reflect, Repr, Resolve, Show, StyleChain, Value,
+    cast, elem, func, scope, ty, Args, CastInfo, Content, Dict, Fold, FromValue,
+    IntoValue, NativeElement, Packed, Reflect, Repr, Resolve, Set, Show, StyleChain,
+    Styles, Value,
 };
-use crate::layout::{Abs, Axes, Axis, Dir, Side};
+use crate::layout::{Abs, Axes, Axis, Dir, Fr, Ratio, Side, StackChild, StackElem};
 use crate::text::TextElem;
 
 /// Aligns content horizontally and vertically.
@@ -22,7 +23,7 @@ use crate::text::TextElem;
 /// Not left nor right, it stands alone \
 /// A work of art, a visual throne
 /// ```
-#[elem(Show)]
+#[elem(Set, Show)]
 pub struct AlignElem {
     /// The [alignment] along both axes.
     ///
@@ -36,26 +37,135 @@ pub struct AlignElem {
     ///   rect(inset: 12pt)[ركن]
     /// )
     /// ```
+    ///
+    /// Vertical alignment is relative to the full height of the region the
+    /// content is in, so content that is alone on a page can be centered on
+    /// both axes, as for a title page. If the content is taller than the
+    /// region, it starts at the top.
+    ///
+    /// ```example
+    /// #set page(height: 4cm)
+    /// #align(center + horizon)[
+    ///   #text(1.5em)[*Title*] \
+    ///   A Subtitle
+    /// ]
+    /// ```
     #[positional]
     #[fold]
     #[default]
     pub alignment: Alignment,
 
+    /// A fractional position to place the content at, instead of a fixed
+    /// alignment.
+    ///
+    /// A ratio or float between `{0%}` and `{100%}` positions the content
+    /// horizontally, where `{0%}` corresponds to `left`, `{50%}` to `center`,
+    /// and `{100%}` to `right`. In between, the position is interpolated:
+    /// At `{25%}`, the space to the left of the content is a quarter of the
+    /// space that is left over next to it. A dictionary with `x` and `y`
+    /// keys sets the position along either or both axes. Along an axis with
+    /// a fractional position, this takes precedence over the `alignment`.
+    ///
+    /// The content is positioned within the full width of its region. Any
+    /// vertical position, even `{0%}`, makes the element fill all of the
+    /// remaining height of its region, so content after it moves to the
+    /// next region.
+    ///
+    /// Unlike the `alignment`, the position can only be passed to the
+    /// `align` function directly and not be configured with a set rule.
+    ///
+    /// ```example
+    /// #align(position: 25%, rect(width: 40pt)[25%])
+    /// #align(position: (x: 75%), rect(width: 40pt)[75%])
+    /// ```
+    pub position: Option<AlignPosition>,
+
     /// The content to align.
     #[required]
     pub body: Content,
 }
 
+impl Set for AlignElem {
+    fn set(_: &mut Engine, args: &mut Args) -> SourceResult<Styles> {
+        // The position is left out on purpose: Set rules only take the
+        // alignment.
+        let mut styles = Styles::new();
+        if let Some(alignment) = args.find()? {
+            styles.set(Self::set_alignment(alignment));
+        }
+        Ok(styles)
+    }
+}
+
 impl Show for Packed<AlignElem> {
     #[typst_macros::time(name = "align", span = self.span())]
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        Ok(self
-            .body()
-            .clone()
-            .styled(AlignElem::set_alignment(self.alignment(styles))))
+        let mut body = self.body().clone();
+
+        // Interpolate fractional positions by distributing fractional
+        // spacing before and after the content.
+        if let Some(AlignPosition(position)) = self.position(styles) {
+            for (dir, ratio) in [(Dir::LTR, position.x), (Dir::TTB, position.y)] {
+                let Some(ratio) = ratio else { continue };
+                let before = Fr::new(ratio.get());
+                let after = Fr::new(1.0 - ratio.get());
+                body = StackElem::new(vec![
+                    StackChild::Spacing(before.into()),
+                    StackChild::Block(body),
+                    StackChild::Spacing(after.into()),
+                ])
+                .with_dir(dir)
+                .pack()
+                .spanned(self.span());
+            }
+        }
+
+        Ok(body.styled(AlignElem::set_alignment(self.alignment(styles))))
+    }
+}
+
+/// A fractional position along one or both axes, at which to align content.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct AlignPosition(pub Axes<Option<Ratio>>);
+
+impl AlignPosition {
+    /// Create a position, checking that the ratios are between zero and one.
+    fn new(x: Option<Ratio>, y: Option<Ratio>) -> StrResult<Self> {
+        let valid = |ratio: Option<Ratio>| {
+            ratio.map_or(true, |ratio| (0.0..=1.0).contains(&ratio.get()))
+        };
+        if !valid(x) || !valid(y) {
+            bail!("position must be between 0% and 100%");
+        }
+        Ok(Self(Axes::new(x, y)))
     }
 }
 
+cast! {
+    AlignPosition,
+    self => match self.0 {
+        Axes { x: Some(x), y: None } => x.into_value(),
+        Axes { x, y } => {
+            let mut dict = Dict::new();
+            if let Some(x) = x {
+                dict.insert("x".into(), x.into_value());
+            }
+            if let Some(y) = y {
+                dict.insert("y".into(), y.into_value());
+            }
+            dict.into_value()
+        }
+    },
+    v: Ratio => Self::new(Some(v), None)?,
+    v: f64 => Self::new(Some(Ratio::new(v)), None)?,
+    mut dict: Dict => {
+        let x = dict.take("x").ok().map(Value::cast).transpose()?;
+        let y = dict.take("y").ok().map(Value::cast).transpose()?;
+        dict.finish(&["x", "y"])?;
+        Self::new(x, y)?
+    },
+}
+
 /// Where to [align] something along an axis.
 ///
 /// Possible values are:
@@ -63,9 +173,9 @@ impl Show for Packed<AlignElem> {
 ///   direction]($text.dir).
 /// - `end`: Aligns at the [end]($direction.end) of the [text
 ///   direction]($text.dir).
-/// - `left`: Align at the left.
+/// - `left`: Aligns at the left, regardless of the text direction.
 /// - `center`: Aligns in the middle, horizontally.
-/// - `right`: Aligns at the right.
+/// - `right`: Aligns at the right, regardless of the text direction.
 /// - `top`: Aligns at the top.
 /// - `horizon`: Aligns in the middle, vertically.
 /// - `bottom`: Align at the bottom.
@@ -78,6 +188,18 @@ impl Show for Packed<AlignElem> {
 /// #align(alignment.center)[Hi]
 /// ```
 ///
+/// # Logical and physical alignments
+/// The `start` and `end` alignments are _logical:_ They follow the [text
+/// direction]($text.dir) and thus swap sides in right-to-left text. In
+/// contrast, `left` and `right` are _physical_ and always refer to the same
+/// side of the page, regardless of the direction.
+///
+/// ```example
+/// #set text(dir: rtl)
+/// #align(start)[Start]
+/// #align(left)[Left]
+/// ```
+///
 /// # 2D alignments
 /// To align along both axes at the same time, add the two alignments using the
 /// `+` operator. For example, `top + right` aligns the content to the top right
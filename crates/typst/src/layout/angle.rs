@@ -115,6 +115,18 @@ impl Angle {
     pub fn to_deg(self) -> f64 {
         self.to_unit(AngleUnit::Deg)
     }
+
+    /// Normalizes this angle to the range `{0deg}` (inclusive) to `{360deg}`
+    /// (exclusive).
+    ///
+    /// ```example
+    /// #(-90deg).normalize() \
+    /// #(450deg).normalize()
+    /// ```
+    #[func]
+    pub fn normalize(self) -> Angle {
+        Self::deg(self.to_deg().rem_euclid(360.0))
+    }
 }
 
 impl Numeric for Angle {
@@ -0,0 +1,76 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
+use crate::layout::{BoxElem, Corners, Em, Fill, Length, Ratio, Rel, Sides};
+use crate::visualize::{Color, Paint, Stroke};
+
+/// A small inline label, such as a tag or a status marker.
+///
+/// A badge is an inline [box]($box) with a small inset, fully rounded ends,
+/// and a subtle background. It sits on the baseline like the surrounding text
+/// and, by default, doesn't affect the height of the line. Each of the
+/// defaults can be overridden through the badge's arguments or a set rule.
+///
+/// # Example
+/// ```example
+/// Status: #badge[stable] \
+/// #set badge(fill: red.lighten(60%))
+/// Status: #badge[deprecated]
+/// ```
+#[elem(Show)]
+pub struct BadgeElem {
+    /// The badge's background color.
+    #[default(Some(Color::from_u8(0xE6, 0xE6, 0xE6, 0xFF).into()))]
+    pub fill: Option<Paint>,
+
+    /// The badge's border color. See the
+    /// [rectangle's documentation]($rect.stroke) for more details.
+    #[fold]
+    pub stroke: Sides<Option<Option<Stroke>>>,
+
+    /// How much to round the badge's corners. The default makes its ends
+    /// fully round.
+    #[fold]
+    #[default(Corners::splat(Some(Ratio::new(0.5).into())))]
+    pub radius: Corners<Option<Rel<Length>>>,
+
+    /// How much to pad the badge's content.
+    #[fold]
+    #[default(Sides::new(
+        Some(Em::new(0.4).into()),
+        Some(Rel::zero()),
+        Some(Em::new(0.4).into()),
+        Some(Rel::zero()),
+    ))]
+    pub inset: Sides<Option<Rel<Length>>>,
+
+    /// How much to expand the badge's background without affecting the
+    /// layout.
+    #[fold]
+    #[default(Sides::new(
+        Some(Rel::zero()),
+        Some(Em::new(0.2).into()),
+        Some(Rel::zero()),
+        Some(Em::new(0.2).into()),
+    ))]
+    pub outset: Sides<Option<Rel<Length>>>,
+
+    /// The badge's content.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<BadgeElem> {
+    #[typst_macros::time(name = "badge", span = self.span())]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Ok(BoxElem::new()
+            .with_fill(self.fill(styles).map(Fill::Paint))
+            .with_stroke(self.stroke(styles))
+            .with_radius(self.radius(styles))
+            .with_inset(self.inset(styles))
+            .with_outset(self.outset(styles))
+            .with_body(Some(self.body().clone()))
+            .pack()
+            .spanned(self.span()))
+    }
+}
@@ -94,6 +94,8 @@ impl LayoutMultiple for Packed<ColumnsElem> {
             last: regions.last,
             expand: Axes::new(true, regions.expand.y),
             root: regions.root,
+            columns,
+            column: 0,
         };
 
         // Layout the children.
@@ -163,7 +165,8 @@ impl LayoutMultiple for Packed<ColumnsElem> {
 #[elem(title = "Column Break", Behave)]
 pub struct ColbreakElem {
     /// If `{true}`, the column break is skipped if the current column is
-    /// already empty.
+    /// already empty. Out-of-flow content like [placed]($place) elements
+    /// doesn't count towards a column's content.
     #[default(false)]
     pub weak: bool,
 }
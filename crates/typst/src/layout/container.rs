@@ -1,14 +1,20 @@
-use crate::diag::SourceResult;
+use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, AutoValue, Content, Packed, Resolve, Smart, StyleChain, Value,
+    cast, dict, elem, AutoValue, Cast, Content, Dict, NativeElement, Packed, Resolve,
+    Smart, StyleChain, Value,
 };
 use crate::layout::{
-    Abs, Axes, Corners, Em, Fr, Fragment, Frame, FrameKind, LayoutMultiple, Length,
-    Ratio, Regions, Rel, Sides, Size, Spacing, VElem,
+    Abs, Alignment, Axes, Corners, Dir, Em, Fr, Fragment, Frame, FrameItem, FrameKind,
+    HAlignment, LayoutMultiple, LayoutSingle, Length, OuterHAlignment, PagebreakElem,
+    Point, Ratio, Regions, Rel, Sides, Size, Spacing, VAlignment, VElem,
+};
+use crate::syntax::Span;
+use crate::text::{Font, Glyph, TextElem, TextItem};
+use crate::util::{MaybeReverseIter, Numeric};
+use crate::visualize::{
+    clip_rect, Color, FixedStroke, ImageElem, Paint, Path, PolygonElem, Stroke,
 };
-use crate::util::Numeric;
-use crate::visualize::{clip_rect, Paint, Stroke};
 
 /// An inline-level container that sizes content.
 ///
@@ -40,11 +46,81 @@ pub struct BoxElem {
     /// ```example
     /// Line in #box(width: 1fr, line(length: 100%)) between.
     /// ```
+    ///
+    /// A relative width like `{100%}` is resolved against the width of the
+    /// box's container, e.g. the page's text area or the inside of a block.
+    /// The box then takes up exactly that width, regardless of its contents,
+    /// whereas an `{auto}` width hugs the contents. Relative widths aren't
+    /// clamped, so a box wider than `{100%}` extends beyond its container.
+    ///
+    /// ```example
+    /// #block(width: 80pt, inset: 4pt, stroke: gray)[
+    ///   #box(width: 100%, fill: aqua)[Full] \
+    ///   #box(fill: aqua)[Hug]
+    /// ]
+    /// ```
+    ///
+    /// An `{auto}` width doesn't mean unlimited space, though: The contents
+    /// are laid out against the available width, so a long paragraph inside
+    /// of an auto-width box wraps at the edge of the container. The box then
+    /// hugs the widest of the resulting lines.
+    ///
+    /// ```example
+    /// #block(width: 120pt)[
+    ///   #box(fill: aqua, lorem(12))
+    /// ]
+    /// ```
     pub width: Sizing,
 
     /// The height of the box.
+    ///
+    /// When a box has a fixed width or height, its contents are laid out in
+    /// a region of exactly that size. This means that [aligned]($align)
+    /// content is aligned within the box along that axis.
+    ///
+    /// ```example
+    /// #box(
+    ///   width: 2cm,
+    ///   height: 1cm,
+    ///   stroke: 0.5pt,
+    ///   align(bottom + right)[x],
+    /// )
+    /// ```
+    ///
+    /// The most common case, vertically centering the contents of a box with
+    /// a fixed height, is covered by the `horizon` alignment. It combines
+    /// with a horizontal alignment as usual.
+    ///
+    /// ```example
+    /// #box(height: 1cm, stroke: 0.5pt, align(horizon)[Centered])
+    /// #box(
+    ///   width: 3cm,
+    ///   height: 1cm,
+    ///   stroke: 0.5pt,
+    ///   align(horizon + right)[Right],
+    /// )
+    /// ```
     pub height: Smart<Rel<Length>>,
 
+    /// The box's aspect ratio, that is, its width divided by its height.
+    ///
+    /// The aspect ratio determines the side that isn't given explicitly:
+    /// - If only the width is given, the height is the width divided by the
+    ///   aspect ratio.
+    /// - If only the height is given, the width is the height multiplied by
+    ///   the aspect ratio.
+    /// - If neither is given, the box becomes as large as possible within the
+    ///   available space while keeping its aspect ratio.
+    /// - If both are given, the aspect ratio has no effect.
+    ///
+    /// The aspect ratio must be positive.
+    ///
+    /// ```example
+    /// #box(width: 2cm, aspect: 16 / 9, fill: aqua)
+    /// #box(height: 1cm, aspect: 1, fill: teal)
+    /// ```
+    pub aspect: Option<f64>,
+
     /// An amount to shift the box's baseline by.
     ///
     /// ```example
@@ -55,16 +131,56 @@ pub struct BoxElem {
 
     /// The box's background color. See the
     /// [rectangle's documentation]($rect.fill) for more details.
-    pub fill: Option<Paint>,
+    ///
+    /// The box can also be filled with an [image]. The image then covers the
+    /// box's area including its [`outset`]($box.outset) according to its
+    /// [`fit`]($image.fit) and is clipped to the box's rounded corners.
+    ///
+    /// ```example
+    /// #box(
+    ///   width: 3cm,
+    ///   height: 1cm,
+    ///   radius: 4pt,
+    ///   fill: image("tiger.jpg", fit: "cover"),
+    /// )
+    /// ```
+    pub fill: Option<Fill>,
 
     /// The box's border color. See the
     /// [rectangle's documentation]($rect.stroke) for more details.
+    ///
+    /// With a dictionary, only the given sides are stroked, while sides that
+    /// are missing or set to `{none}` are left out. Where two adjacent sides
+    /// have the same stroke, they are drawn as one continuous line with a
+    /// proper join at the corner.
+    ///
+    /// ```example
+    /// #box(stroke: (bottom: 1pt), inset: (bottom: 2pt))[Underlined]
+    /// #box(stroke: (left: 2pt + blue, bottom: 2pt + blue), inset: 4pt)[Ruled]
+    /// ```
     #[resolve]
     #[fold]
     pub stroke: Sides<Option<Option<Stroke>>>,
 
     /// How much to round the box's corners. See the
     /// [rectangle's documentation]($rect.radius) for more details.
+    ///
+    /// With a dictionary, each corner can be rounded differently. Corners that
+    /// are not mentioned stay square. Each radius is clamped to half of the
+    /// box's smaller dimension on its own, so that neighbouring corners never
+    /// overlap.
+    ///
+    /// ```example
+    /// #box(
+    ///   fill: aqua,
+    ///   inset: 6pt,
+    ///   radius: (top: 6pt, bottom-right: 6pt),
+    /// )[Tab] and a #box(
+    ///   fill: yellow,
+    ///   inset: 6pt,
+    ///   radius: (top-left: 100pt, top-right: 8pt, bottom-right: 8pt),
+    /// )[Speech bubble]
+    /// ```
     #[resolve]
     #[fold]
     pub radius: Corners<Option<Rel<Length>>>,
@@ -100,15 +216,81 @@ pub struct BoxElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A hard-edged drop shadow to draw behind the box.
+    ///
+    /// Can be a paint, in which case the shadow is offset by `{2pt}` on both
+    /// axes, or a dictionary with any of the keys `fill`, `dx`, and `dy`. The
+    /// shadow follows the box's outset and rounded corners. It doesn't affect
+    /// the box's size and may thus overlap the surrounding content.
+    ///
+    /// ```example
+    /// #box(
+    ///   inset: 8pt,
+    ///   radius: 4pt,
+    ///   fill: white,
+    ///   stroke: luma(200),
+    ///   shadow: (fill: luma(180), dx: 3pt, dy: 3pt),
+    /// )[Card]
+    /// ```
+    pub shadow: Option<Shadow>,
+
     /// Whether to clip the content inside the box.
     #[default(false)]
     pub clip: bool,
 
+    /// A polygon to clip the box to.
+    ///
+    /// Can be an array of points or a [polygon]($polygon), whose fill and
+    /// stroke are ignored. The points are relative to the box's top-left
+    /// corner and relative lengths are resolved against the box's size. The
+    /// polygon is closed automatically. Unlike [`clip`]($box.clip), this also
+    /// clips the box's own fill and stroke, but not its shadow.
+    ///
+    /// ```example
+    /// #box(
+    ///   width: 40pt,
+    ///   height: 40pt,
+    ///   clip-path: polygon.regular(size: 40pt, vertices: 6),
+    ///   fill: aqua,
+    /// )
+    /// #box(
+    ///   clip-path: ((50%, 0%), (100%, 100%), (0%, 100%)),
+    ///   image("tiger.jpg", width: 40pt),
+    /// )
+    /// ```
+    pub clip_path: Option<ClipPath>,
+
+    /// How to deal with content that is wider than the box.
+    ///
+    /// By default, such content overflows the box. With `{"ellipsis"}`, the
+    /// content is laid out on a single line and, if it doesn't fit, cut off
+    /// and followed by an ellipsis. This only has an effect if the box has a
+    /// fixed width.
+    ///
+    /// ```example
+    /// #box(
+    ///   width: 80pt,
+    ///   overflow: "ellipsis",
+    /// )[A rather long title]
+    /// ```
+    #[default(BoxOverflow::Visible)]
+    pub overflow: BoxOverflow,
+
     /// The contents of the box.
     #[positional]
     pub body: Option<Content>,
 }
 
+/// How a box deals with content that is wider than itself.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum BoxOverflow {
+    /// The content extends beyond the box.
+    #[default]
+    Visible,
+    /// The content is cut off and followed by an ellipsis.
+    Ellipsis,
+}
+
 impl Packed<BoxElem> {
     #[typst_macros::time(name = "box", span = self.span())]
     pub fn layout(
@@ -125,12 +307,38 @@ impl Packed<BoxElem> {
 
         // Resolve the sizing to a concrete size.
         let sizing = Axes::new(width, self.height(styles));
-        let expand = sizing.as_ref().map(Smart::is_custom);
-        let size = sizing
+        let mut expand = sizing.as_ref().map(Smart::is_custom);
+        let mut size = sizing
             .resolve(styles)
             .zip_map(regions.base(), |s, b| s.map(|v| v.relative_to(b)))
             .unwrap_or(regions.base());
 
+        // Derive the missing sides from the aspect ratio.
+        if let Some(aspect) = self.aspect(styles) {
+            if !(aspect > 0.0 && aspect.is_finite()) {
+                bail!(self.span(), "aspect ratio must be positive");
+            }
+
+            match (expand.x, expand.y) {
+                (true, true) => {}
+                (true, false) => size.y = size.x / aspect,
+                (false, true) => size.x = size.y * aspect,
+                (false, false) => {
+                    let width = size.x.min(size.y * aspect);
+                    if !width.is_finite() {
+                        bail!(
+                            self.span(),
+                            "cannot expand box with aspect ratio into infinite space";
+                            hint: "try giving the box a width or height"
+                        );
+                    }
+                    size = Size::new(width, width / aspect);
+                }
+            }
+
+            expand = Axes::splat(true);
+        }
+
         // Apply inset.
         let mut body = self.body(styles).unwrap_or_default();
         let inset = self.inset(styles).unwrap_or_default();
@@ -139,10 +347,31 @@ impl Packed<BoxElem> {
         }
 
         // Select the appropriate base and expansion for the child depending
-        // on whether it is automatically or relatively sized.
-        let pod = Regions::one(size, expand);
+        // on whether it is automatically or relatively sized. Content that
+        // may be ellipsized is laid out on a single line.
+        let ellipsized = expand.x && self.overflow(styles) == BoxOverflow::Ellipsis;
+        let pod = if ellipsized {
+            Regions::one(Size::new(Abs::inf(), size.y), Axes::new(false, expand.y))
+        } else {
+            Regions::one(size, expand)
+        };
         let mut frame = body.layout(engine, styles, pod)?.into_frame();
 
+        // Cut off content that doesn't fit.
+        if ellipsized && frame.width() > size.x {
+            if TextElem::dir_in(styles) == Dir::RTL {
+                // Right-to-left content starts at the right edge of the box,
+                // so it is cut off on the left.
+                let left = inset.left.relative_to(size.x);
+                let shift = frame.width() - size.x;
+                ellipsize(&mut frame, shift + left, true);
+                frame.translate(Point::with_x(-shift));
+            } else {
+                let right = inset.right.relative_to(size.x);
+                ellipsize(&mut frame, size.x - right, false);
+            }
+        }
+
         // Enforce correct size.
         *frame.size_mut() = expand.select(size, frame.size());
 
@@ -172,7 +401,37 @@ impl Packed<BoxElem> {
         if fill.is_some() || stroke.iter().any(Option::is_some) {
             let outset = self.outset(styles).unwrap_or_default();
             let radius = self.radius(styles).unwrap_or_default();
-            frame.fill_and_stroke(fill, stroke, outset, radius, self.span());
+            fill_and_stroke(
+                &mut frame,
+                engine,
+                styles,
+                fill,
+                stroke,
+                outset,
+                radius,
+                self.span(),
+            )?;
+        }
+
+        // Clip the contents, fill, and stroke to a custom path.
+        if let Some(clip_path) = self.clip_path(styles) {
+            frame.clip(clip_path.to_path(styles, frame.size()));
+        }
+
+        // Add the shadow behind everything else.
+        if let Some(shadow) = self.shadow(styles) {
+            let outset = self.outset(styles).unwrap_or_default();
+            let radius = self.radius(styles).unwrap_or_default();
+            let mut area = Frame::soft(frame.size());
+            area.fill_and_stroke(
+                Some(shadow.fill),
+                Sides::splat(None),
+                outset,
+                radius,
+                self.span(),
+            );
+            let offset = Point::new(shadow.dx.resolve(styles), shadow.dy.resolve(styles));
+            frame.prepend_frame(offset, area);
         }
 
         // Apply metadata.
@@ -255,9 +514,44 @@ pub struct BlockElem {
     #[default(true)]
     pub breakable: bool,
 
+    /// The minimum number of lines of a breakable block that must fit into
+    /// the current region. If fewer lines fit, the whole block moves to the
+    /// next region instead of breaking. Has no effect on blocks with a fixed
+    /// height.
+    ///
+    /// Lines are counted as the distinct heights of text baselines in the part
+    /// of the block that fits. Text side by side, e.g. in the columns of a
+    /// grid, thus only counts as one line and content without text, like
+    /// images, doesn't count at all.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #lorem(10)
+    /// #block(min-lines-before-break: 3, lorem(15))
+    /// ```
+    pub min_lines_before_break: usize,
+
+    /// A marker to show at the bottom end of each piece of the block that is
+    /// continued in the next region. The marker is drawn on top of the block's
+    /// content and doesn't take up any space.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #block(
+    ///   inset: 4pt,
+    ///   stroke: gray,
+    ///   continuation: text(8pt)[→],
+    ///   lorem(20),
+    /// )
+    /// ```
+    pub continuation: Option<Content>,
+
     /// The block's background color. See the
     /// [rectangle's documentation]($rect.fill) for more details.
-    pub fill: Option<Paint>,
+    ///
+    /// Like a [box's fill]($box.fill), this can also be an [image]. When the
+    /// block breaks across regions, each of its frames is filled separately.
+    pub fill: Option<Fill>,
 
     /// The block's border color. See the
     /// [rectangle's documentation]($rect.stroke) for more details.
@@ -301,6 +595,27 @@ pub struct BlockElem {
     /// The spacing between this block and its predecessor. Takes precedence
     /// over `spacing`. Can be used in combination with a show rule to adjust
     /// the spacing around arbitrary block-level elements.
+    ///
+    /// The spacing around blocks collapses: Between two blocks, only the
+    /// larger of the first one's `below` and the second one's `above` spacing
+    /// is used. A [weak]($v.weak) `v` between them replaces both, and at the
+    /// start and end of the container, the spacing is removed. Components
+    /// can thus bring their own spacing without adding up with that of their
+    /// neighbours.
+    ///
+    /// ```example
+    /// #let note(body) = block(
+    ///   above: 16pt,
+    ///   below: 4pt,
+    ///   fill: luma(230),
+    ///   inset: 4pt,
+    ///   body,
+    /// )
+    ///
+    /// Some text.
+    /// #note[A note with space above.]
+    /// #note[Another one.]
+    /// ```
     #[external]
     #[default(Em::new(1.2).into())]
     pub above: Spacing,
@@ -328,10 +643,58 @@ pub struct BlockElem {
     #[default(VElem::block_spacing(Em::new(1.2).into()))]
     pub below: VElem,
 
+    /// The minimum distance from the last baseline before the block to the
+    /// block's first baseline.
+    ///
+    /// While `above` and `below` measure the spacing between the edges of
+    /// the block and its predecessor, this measures from baseline to
+    /// baseline, which gives a more even vertical rhythm. The usual spacing,
+    /// including its collapsing, is applied first. If the baselines then end
+    /// up closer than `lead`, additional space is inserted above the block.
+    /// The lead never reduces the spacing and has no effect at the start of
+    /// a page or container. Content without text uses its bottom edge as its
+    /// last and its baseline as its first baseline.
+    ///
+    /// ```example
+    /// #set block(spacing: 0pt)
+    /// #block(fill: aqua)[Default]
+    /// #block(lead: 24pt, fill: aqua)[With lead]
+    /// ```
+    #[resolve]
+    pub lead: Option<Length>,
+
     /// Whether to clip the content inside the block.
     #[default(false)]
     pub clip: bool,
 
+    /// Whether to float the block at the side of the column.
+    ///
+    /// A floating block is placed at the given side of the column at its
+    /// position in the flow. The lines of the paragraphs that follow it are
+    /// shortened so that the text flows beside the block. Once the text has
+    /// passed the block, lines resume their full width. Other blocks never
+    /// flow beside a floating block; they are moved below it instead.
+    ///
+    /// A floating block is never broken across pages.
+    ///
+    /// ```example
+    /// #set page(height: 160pt)
+    /// #block(
+    ///   float: left,
+    ///   width: 40pt,
+    ///   height: 60pt,
+    ///   fill: aqua,
+    /// )
+    /// #lorem(40)
+    /// ```
+    pub float: Option<OuterHAlignment>,
+
+    /// The horizontal spacing between a floating block and the text that flows
+    /// beside it.
+    #[resolve]
+    #[default(Em::new(1.0).into())]
+    pub clearance: Length,
+
     /// The contents of the block.
     #[positional]
     pub body: Option<Content>,
@@ -371,10 +734,20 @@ impl LayoutMultiple for Packed<BlockElem> {
         // Layout the child.
         let mut frames = if self.breakable(styles) {
             // Measure to ensure frames for all regions have the same width.
+            // A body with pagebreaks is measured in all regions since forced
+            // breaks are only possible with more than one region.
             if sizing.x == Smart::Auto {
-                let pod = Regions::one(size, Axes::splat(false));
-                let frame = body.measure(engine, styles, pod)?.into_frame();
-                size.x = frame.width();
+                if body.query_first(PagebreakElem::elem().select()).is_some() {
+                    let mut pod = regions;
+                    pod.size.x = size.x;
+                    pod.expand = Axes::splat(false);
+                    let fragment = body.measure(engine, styles, pod)?;
+                    size.x = fragment.iter().map(Frame::width).max().unwrap_or_default();
+                } else {
+                    let pod = Regions::one(size, Axes::splat(false));
+                    let frame = body.measure(engine, styles, pod)?.into_frame();
+                    size.x = frame.width();
+                }
                 expand.x = true;
             }
 
@@ -409,6 +782,24 @@ impl LayoutMultiple for Packed<BlockElem> {
             }
 
             let mut frames = body.layout(engine, styles, pod)?.into_frames();
+
+            // Move the whole block to the next region if too few of its lines
+            // would remain in the current one.
+            let min_lines = self.min_lines_before_break(styles);
+            if min_lines > 0
+                && heights.is_empty()
+                && frames.len() > 1
+                && !frames[0].is_empty()
+                && count_baselines(&frames[0]) < min_lines
+            {
+                let mut next = pod;
+                next.next();
+                let rest = body.layout(engine, styles, next)?.into_frames();
+                frames = std::iter::once(Frame::soft(Size::new(size.x, Abs::zero())))
+                    .chain(rest)
+                    .collect();
+            }
+
             for (frame, &height) in frames.iter_mut().zip(&heights) {
                 *frame.size_mut() =
                     expand.select(Size::new(size.x, height), frame.size());
@@ -439,6 +830,24 @@ impl LayoutMultiple for Packed<BlockElem> {
             }
         }
 
+        // Mark the pieces that are continued in the next region.
+        if let Some(marker) = self.continuation(styles) {
+            let align =
+                Alignment::Both(HAlignment::End, VAlignment::Bottom).resolve(styles);
+            let count = frames.len();
+            for frame in frames.iter_mut().take(count.saturating_sub(1)) {
+                if frame.is_empty() {
+                    continue;
+                }
+
+                let pod = Regions::one(frame.size(), Axes::splat(false));
+                let mark = marker.layout(engine, styles, pod)?.into_frame();
+                let free = frame.size() - mark.size();
+                let pos = Point::new(align.x.position(free.x), align.y.position(free.y));
+                frame.push_frame(pos, mark);
+            }
+        }
+
         // Add fill and/or stroke.
         if fill.is_some() || stroke.iter().any(Option::is_some) {
             let mut skip = false;
@@ -449,13 +858,16 @@ impl LayoutMultiple for Packed<BlockElem> {
             let outset = self.outset(styles).unwrap_or_default();
             let radius = self.radius(styles).unwrap_or_default();
             for frame in frames.iter_mut().skip(skip as usize) {
-                frame.fill_and_stroke(
+                fill_and_stroke(
+                    frame,
+                    engine,
+                    styles,
                     fill.clone(),
                     stroke.clone(),
                     outset,
                     radius,
                     self.span(),
-                );
+                )?;
             }
         }
 
@@ -468,6 +880,310 @@ impl LayoutMultiple for Packed<BlockElem> {
     }
 }
 
+/// How to fill the background of a box or block.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum Fill {
+    /// Fill with a color, gradient, or pattern.
+    Paint(Paint),
+    /// Fill with an image that is laid out to the size of the container.
+    Image(Packed<ImageElem>),
+}
+
+cast! {
+    Fill,
+    self => match self {
+        Self::Paint(paint) => paint.into_value(),
+        Self::Image(image) => image.pack().into_value(),
+    },
+    paint: Paint => Self::Paint(paint),
+    content: Content => match content.into_packed::<ImageElem>() {
+        Ok(image) => Self::Image(image),
+        Err(_) => bail!("only images can be used as a fill"),
+    },
+}
+
+/// A hard-edged drop shadow behind a box.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Shadow {
+    /// How to fill the shadow.
+    pub fill: Paint,
+    /// The horizontal offset of the shadow.
+    pub dx: Length,
+    /// The vertical offset of the shadow.
+    pub dy: Length,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self {
+            fill: Color::BLACK.with_alpha(0.25).into(),
+            dx: Abs::pt(2.0).into(),
+            dy: Abs::pt(2.0).into(),
+        }
+    }
+}
+
+cast! {
+    Shadow,
+    self => dict! { "fill" => self.fill, "dx" => self.dx, "dy" => self.dy }.into_value(),
+    fill: Paint => Self { fill, ..Self::default() },
+    mut dict: Dict => {
+        let mut shadow = Self::default();
+        if let Ok(fill) = dict.take("fill") {
+            shadow.fill = fill.cast()?;
+        }
+        if let Ok(dx) = dict.take("dx") {
+            shadow.dx = dx.cast()?;
+        }
+        if let Ok(dy) = dict.take("dy") {
+            shadow.dy = dy.cast()?;
+        }
+        dict.finish(&["fill", "dx", "dy"])?;
+        shadow
+    },
+}
+
+/// A polygon to clip a box's contents to.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ClipPath(pub Vec<Axes<Rel<Length>>>);
+
+impl ClipPath {
+    /// Build a closed path through the points, relative to the given size.
+    fn to_path(&self, styles: StyleChain, size: Size) -> Path {
+        let mut path = Path::new();
+        for (i, vertex) in self.0.iter().enumerate() {
+            let point = vertex.resolve(styles).zip_map(size, Rel::relative_to).to_point();
+            if i == 0 {
+                path.move_to(point);
+            } else {
+                path.line_to(point);
+            }
+        }
+        path.close_path();
+        path
+    }
+}
+
+cast! {
+    ClipPath,
+    self => self.0.into_value(),
+    vertices: Vec<Axes<Rel<Length>>> => Self(vertices),
+    content: Content => match content.into_packed::<PolygonElem>() {
+        Ok(polygon) => Self(polygon.vertices().clone()),
+        Err(_) => bail!("only polygons can be used as a clip path"),
+    },
+}
+
+/// Add a fill and stroke to a container's frame.
+///
+/// Image fills are laid out to the frame's size plus the outset and clipped
+/// to the rounded rectangle the stroke is drawn along.
+#[allow(clippy::too_many_arguments)]
+fn fill_and_stroke(
+    frame: &mut Frame,
+    engine: &mut Engine,
+    styles: StyleChain,
+    fill: Option<Fill>,
+    stroke: Sides<Option<FixedStroke>>,
+    outset: Sides<Rel<Abs>>,
+    radius: Corners<Rel<Abs>>,
+    span: Span,
+) -> SourceResult<()> {
+    let image = match fill {
+        Some(Fill::Image(image)) => image,
+        Some(Fill::Paint(paint)) => {
+            frame.fill_and_stroke(Some(paint), stroke, outset, radius, span);
+            return Ok(());
+        }
+        None => {
+            frame.fill_and_stroke(None, stroke, outset, radius, span);
+            return Ok(());
+        }
+    };
+
+    let resolved = outset.relative_to(frame.size());
+    let size = frame.size() + resolved.sum_by_axis();
+    let clip = clip_rect(size, radius, &stroke);
+    frame.fill_and_stroke(None, stroke, outset, radius, span);
+
+    // The image sits below the stroke, so it must be prepended afterwards.
+    let pod = Regions::one(size, Axes::splat(true));
+    let mut background = image.layout(engine, styles, pod)?;
+    background.clip(clip);
+    frame.prepend_frame(Point::new(-resolved.left, -resolved.top), background);
+    Ok(())
+}
+
+/// Cut off the contents of a frame at the first text run that doesn't fit
+/// into `limit` together with an ellipsis and append the ellipsis to that
+/// run. Returns whether the ellipsis was placed.
+///
+/// In right-to-left text, whose logical end is on the left, the contents
+/// are instead cut off such that they don't start before `limit` and the
+/// ellipsis is placed in front of the run.
+fn ellipsize(frame: &mut Frame, limit: Abs, rtl: bool) -> bool {
+    // Whether a text run at the given position needs to be shortened.
+    let overflows = |x: Abs, text: &TextItem| {
+        if rtl {
+            x - ellipsis_width(text) < limit
+        } else {
+            x + text.width() + ellipsis_width(text) > limit
+        }
+    };
+
+    // Whether an item at the given position lies beyond the limit.
+    let beyond = |x: Abs| if rtl { x < limit } else { x >= limit };
+
+    let mut items = vec![];
+    let mut done = false;
+    for (pos, item) in frame.items().rev_if(rtl) {
+        match item {
+            // Keep metadata so that introspection still finds the content.
+            FrameItem::Meta(..) => items.push((*pos, item.clone())),
+            _ if done => {}
+            FrameItem::Group(group) if group.transform.is_identity() => {
+                let mut group = group.clone();
+                done = ellipsize(&mut group.frame, limit - pos.x, rtl);
+                items.push((*pos, FrameItem::Group(group)));
+            }
+            FrameItem::Text(text) if overflows(pos.x, text) => {
+                let mut text = text.clone();
+                let mut pos = *pos;
+                if rtl {
+                    // Keep the run's right edge in place.
+                    let width = text.width();
+                    truncate_text(&mut text, pos.x + width - limit, true);
+                    pos.x += width - text.width();
+                } else {
+                    truncate_text(&mut text, limit - pos.x, false);
+                }
+                items.push((pos, FrameItem::Text(text)));
+                done = true;
+            }
+            _ if beyond(pos.x) => {}
+            _ => items.push((*pos, item.clone())),
+        }
+    }
+
+    let mut output = Frame::new(frame.size(), frame.kind());
+    if frame.has_baseline() {
+        output.set_baseline(frame.baseline());
+    }
+    for (pos, item) in items.into_iter().rev_if(rtl) {
+        output.push(pos, item);
+    }
+
+    *frame = output;
+    done
+}
+
+/// Shorten a text run such that it fits into `limit` together with an
+/// ellipsis and add the ellipsis at the run's logical end.
+///
+/// The glyphs of a run are in visual order, so a right-to-left run keeps its
+/// last glyphs and gets the ellipsis in front of them.
+fn truncate_text(text: &mut TextItem, limit: Abs, rtl: bool) {
+    let (ellipsis, glyphs) = ellipsis_glyphs(&text.font);
+    let reserved = glyphs.iter().map(|&(_, advance)| advance).sum::<Em>();
+    let span = text.glyphs.first().map_or((Span::detached(), 0), |g| g.span);
+
+    let mut x = reserved.at(text.size);
+    let mut keep = 0;
+    for glyph in text.glyphs.iter().rev_if(rtl) {
+        x += glyph.x_advance.at(text.size);
+        if x > limit {
+            break;
+        }
+        keep += 1;
+    }
+
+    if rtl {
+        let len = text.glyphs.len();
+        text.glyphs.drain(..len - keep);
+    } else {
+        text.glyphs.truncate(keep);
+    }
+
+    // Don't leave whitespace in front of the ellipsis.
+    loop {
+        let edge = if rtl { text.glyphs.first() } else { text.glyphs.last() };
+        if !edge.is_some_and(|g| text.text[g.range()].chars().all(char::is_whitespace)) {
+            break;
+        }
+        if rtl {
+            text.glyphs.remove(0);
+        } else {
+            text.glyphs.pop();
+        }
+    }
+
+    let end = text.glyphs.iter().map(|g| g.range().end).max().unwrap_or(0);
+    let mut plain = text.text[..end].to_string();
+    let mut marker = vec![];
+    for &(id, advance) in &glyphs {
+        let start = plain.len();
+        plain.push_str(ellipsis);
+        marker.push(Glyph {
+            id,
+            x_advance: advance,
+            x_offset: Em::zero(),
+            range: start as u16..plain.len() as u16,
+            span,
+        });
+    }
+
+    if rtl {
+        text.glyphs.splice(0..0, marker);
+    } else {
+        text.glyphs.extend(marker);
+    }
+    text.text = plain.into();
+}
+
+/// The width of the ellipsis in a text run's font.
+fn ellipsis_width(text: &TextItem) -> Abs {
+    let (_, glyphs) = ellipsis_glyphs(&text.font);
+    glyphs.iter().map(|&(_, advance)| advance).sum::<Em>().at(text.size)
+}
+
+/// The glyphs that make up an ellipsis in the given font, falling back to
+/// three periods if the font has no ellipsis character.
+fn ellipsis_glyphs(font: &Font) -> (&'static str, Vec<(u16, Em)>) {
+    let glyph = |c: char| {
+        let id = font.ttf().glyph_index(c)?.0;
+        Some((id, font.advance(id)?))
+    };
+
+    if let Some(glyph) = glyph('…') {
+        ("…", vec![glyph])
+    } else {
+        (".", glyph('.').map(|glyph| vec![glyph; 3]).unwrap_or_default())
+    }
+}
+
+/// Count the distinct vertical positions of text baselines in a frame. This
+/// approximates the number of lines, as lines aren't recorded in frames.
+fn count_baselines(frame: &Frame) -> usize {
+    fn collect(frame: &Frame, offset: Point, baselines: &mut Vec<Abs>) {
+        for (pos, item) in frame.items() {
+            let pos = offset + *pos;
+            match item {
+                FrameItem::Group(group) => collect(&group.frame, pos, baselines),
+                FrameItem::Text(_) => {
+                    if !baselines.iter().any(|y| y.approx_eq(pos.y)) {
+                        baselines.push(pos.y);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut baselines = vec![];
+    collect(frame, Point::zero(), &mut baselines);
+    baselines.len()
+}
+
 /// Defines how to size a grid cell along an axis.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Sizing {
@@ -514,3 +1230,107 @@ cast! {
     v: Rel<Length> => Self::Rel(v),
     v: Fr => Self::Fr(v),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::Bytes;
+    use crate::text::Lang;
+    use crate::visualize::PathItem;
+
+    /// Shape a run with one glyph per character, in visual order.
+    fn text_item(text: &str, rtl: bool) -> TextItem {
+        let font = typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .find(|font| text.chars().all(|c| font.ttf().glyph_index(c).is_some()))
+            .unwrap();
+        let mut glyphs: Vec<Glyph> = text
+            .char_indices()
+            .map(|(i, c)| {
+                let id = font.ttf().glyph_index(c).unwrap().0;
+                Glyph {
+                    id,
+                    x_advance: font.advance(id).unwrap(),
+                    x_offset: Em::zero(),
+                    range: i as u16..(i + c.len_utf8()) as u16,
+                    span: (Span::detached(), 0),
+                }
+            })
+            .collect();
+        if rtl {
+            glyphs.reverse();
+        }
+        TextItem {
+            font,
+            size: Abs::pt(10.0),
+            fill: Paint::Solid(Color::BLACK),
+            stroke: None,
+            lang: Lang::ENGLISH,
+            text: text.into(),
+            glyphs,
+        }
+    }
+
+    /// The width of the given glyphs, with room for an ellipsis.
+    fn width_with_ellipsis(text: &TextItem, glyphs: &[Glyph]) -> Abs {
+        glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(text.size)
+            + ellipsis_width(text)
+    }
+
+    #[test]
+    fn test_truncate_text_ltr() {
+        let mut text = text_item("abc def", false);
+        let limit = width_with_ellipsis(&text, &text.glyphs[..4]);
+        let (ellipsis, marker) = ellipsis_glyphs(&text.font);
+        truncate_text(&mut text, limit, false);
+
+        // The space before the cut is dropped and the ellipsis follows.
+        assert_eq!(text.text, format!("abc{ellipsis}"));
+        let ids: Vec<u16> = text.glyphs.iter().map(|g| g.id).collect();
+        let mut expected: Vec<u16> =
+            text_item("abc", false).glyphs.iter().map(|g| g.id).collect();
+        expected.extend(marker.iter().map(|&(id, _)| id));
+        assert_eq!(ids, expected);
+        assert_eq!(text.glyphs[3].range.start, 3);
+        assert!(text.width() <= limit);
+    }
+
+    #[test]
+    fn test_truncate_text_rtl() {
+        let mut text = text_item("abc def", true);
+        let limit = width_with_ellipsis(&text, &text.glyphs[3..]);
+        let (ellipsis, marker) = ellipsis_glyphs(&text.font);
+        truncate_text(&mut text, limit, true);
+
+        // The logical start is kept on the right and the ellipsis is put in
+        // front of it visually, but after it logically.
+        assert_eq!(text.text, format!("abc{ellipsis}"));
+        let ids: Vec<u16> = text.glyphs.iter().map(|g| g.id).collect();
+        let mut expected: Vec<u16> = marker.iter().map(|&(id, _)| id).collect();
+        expected.extend(text_item("abc", true).glyphs.iter().map(|g| g.id));
+        assert_eq!(ids, expected);
+        assert_eq!(text.glyphs[0].range.start, 3);
+        assert_eq!(text.glyphs.last().unwrap().range, 0..1);
+        assert!(text.width() <= limit);
+    }
+
+    #[test]
+    fn test_clip_path_resolves_relative_to_size() {
+        let clip_path = ClipPath(vec![
+            Axes::new(Ratio::new(0.5).into(), Rel::zero()),
+            Axes::new(Ratio::one().into(), Ratio::one().into()),
+            Axes::new(Abs::pt(10.0).into(), Ratio::one().into()),
+        ]);
+        let size = Size::new(Abs::pt(40.0), Abs::pt(20.0));
+        let path = clip_path.to_path(StyleChain::default(), size);
+        assert_eq!(
+            path.0,
+            vec![
+                PathItem::MoveTo(Point::new(Abs::pt(20.0), Abs::zero())),
+                PathItem::LineTo(Point::new(Abs::pt(40.0), Abs::pt(20.0))),
+                PathItem::LineTo(Point::new(Abs::pt(10.0), Abs::pt(20.0))),
+                PathItem::ClosePath,
+            ]
+        );
+    }
+}
@@ -50,6 +50,42 @@ impl Dir {
     pub const TTB: Self = Self::TTB;
     pub const BTT: Self = Self::BTT;
 
+    /// Returns a direction from a starting point.
+    ///
+    /// ```example
+    /// #direction.from(left) \
+    /// #direction.from(right) \
+    /// #direction.from(top) \
+    /// #direction.from(bottom)
+    /// ```
+    #[func]
+    pub const fn from(side: Side) -> Dir {
+        match side {
+            Side::Left => Self::LTR,
+            Side::Right => Self::RTL,
+            Side::Top => Self::TTB,
+            Side::Bottom => Self::BTT,
+        }
+    }
+
+    /// Returns a direction from an end point.
+    ///
+    /// ```example
+    /// #direction.to(left) \
+    /// #direction.to(right) \
+    /// #direction.to(top) \
+    /// #direction.to(bottom)
+    /// ```
+    #[func]
+    pub const fn to(side: Side) -> Dir {
+        match side {
+            Side::Right => Self::LTR,
+            Side::Left => Self::RTL,
+            Side::Bottom => Self::TTB,
+            Side::Top => Self::BTT,
+        }
+    }
+
     /// The axis this direction belongs to, either `{"horizontal"}` or
     /// `{"vertical"}`.
     ///
@@ -101,13 +137,22 @@ impl Dir {
         }
     }
 
-    /// The inverse direction.
+    /// The inverse direction, pointing the other way along the same axis.
+    ///
+    /// Together with [`direction.from`]($direction.from) and
+    /// [`direction.to`]($direction.to), this lets you compute directions to
+    /// pass to elements like [`stack`]($stack).
     ///
     /// ```example
     /// #ltr.inv() \
     /// #rtl.inv() \
     /// #ttb.inv() \
     /// #btt.inv()
+    ///
+    /// #let dir = ltr
+    /// #stack(dir: dir)[A][B][C]
+    /// #stack(dir: dir.inv())[A][B][C]
+    /// #stack(dir: direction.to(dir.start()))[A][B][C]
     /// ```
     #[func(title = "Inverse")]
     pub const fn inv(self) -> Dir {
@@ -7,12 +7,13 @@ use crate::foundations::{
 };
 use crate::introspection::{Meta, MetaElem};
 use crate::layout::{
-    Abs, AlignElem, Axes, BlockElem, ColbreakElem, ColumnsElem, FixedAlignment, Fr,
-    Fragment, Frame, FrameItem, LayoutMultiple, LayoutSingle, PlaceElem, Point, Regions,
-    Rel, Size, Spacing, VElem,
+    Abs, AlignElem, Axes, BlockElem, ColbreakElem, ColumnsElem, Exclusion,
+    FixedAlignment, Fr, Fragment, Frame, FrameItem, LayoutMultiple, LayoutSingle,
+    OuterHAlignment, PagebreakElem, PlaceElem, Point, Regions, Rel, Size, Spacing, VElem,
 };
 use crate::model::{FootnoteElem, FootnoteEntry, ParElem};
 use crate::util::Numeric;
+use crate::visualize::Path;
 
 /// Arranges spacing, paragraphs and block-level elements into a flow.
 ///
@@ -55,13 +56,17 @@ impl LayoutMultiple for Packed<FlowElem> {
                 layouter.layout_spacing(engine, elem, styles)?;
             } else if let Some(placed) = child.to_packed::<PlaceElem>() {
                 layouter.layout_placed(engine, placed, styles)?;
-            } else if child.is::<ColbreakElem>() {
-                if !layouter.regions.backlog.is_empty() || layouter.regions.last.is_some()
-                {
-                    layouter.finish_region(engine, true)?;
-                }
+            } else if let Some(pagebreak) = child.to_packed::<PagebreakElem>() {
+                layouter.layout_pagebreak(engine, pagebreak, styles)?;
+            } else if let Some(colbreak) = child.to_packed::<ColbreakElem>() {
+                layouter.layout_colbreak(engine, colbreak, styles)?;
             } else if let Some(elem) = child.to_packed::<ParElem>() {
                 layouter.layout_par(engine, elem, styles)?;
+            } else if let Some((block, side)) = child
+                .to_packed::<BlockElem>()
+                .and_then(|block| Some((block, block.float(styles)?)))
+            {
+                layouter.layout_side_float(engine, block, side, styles)?;
             } else if let Some(layoutable) = child.with::<dyn LayoutSingle>() {
                 layouter.layout_single(engine, layoutable, styles)?;
             } else if child.can::<dyn LayoutMultiple>() {
@@ -107,6 +112,20 @@ struct FlowLayouter<'a> {
     footnote_config: FootnoteConfig,
     /// Finished frames for previous regions.
     finished: Vec<Frame>,
+    /// A block floating at the side of the flow that paragraphs wrap around.
+    side_float: Option<SideFloat>,
+}
+
+/// A block floating at the side of the flow.
+struct SideFloat {
+    /// The float's frame until it is placed in front of the next child.
+    frame: Option<Frame>,
+    /// The side of the flow at which the float is placed.
+    align: FixedAlignment,
+    /// The horizontal space the float takes up, including clearance.
+    width: Abs,
+    /// The remaining height of the region below the float, once it is placed.
+    bottom: Abs,
 }
 
 /// Cached footnote configuration.
@@ -135,6 +154,7 @@ enum FlowItem {
         delta: Axes<Rel<Abs>>,
         float: bool,
         clearance: Abs,
+        clip: bool,
     },
     /// A footnote frame (can also be the separator).
     Footnote(Frame),
@@ -190,6 +210,7 @@ impl<'a> FlowLayouter<'a> {
                 gap: FootnoteEntry::gap_in(styles),
             },
             finished: vec![],
+            side_float: None,
         }
     }
 
@@ -212,6 +233,14 @@ impl<'a> FlowLayouter<'a> {
         v: &Packed<VElem>,
         styles: StyleChain,
     ) -> SourceResult<()> {
+        // The float is placed in front of the next child, so weak spacing
+        // between the float and that child is not needed.
+        if v.weakness(styles) > 0
+            && self.side_float.as_ref().is_some_and(|float| float.frame.is_some())
+        {
+            return Ok(());
+        }
+
         self.layout_item(
             engine,
             match v.amount() {
@@ -224,6 +253,95 @@ impl<'a> FlowLayouter<'a> {
         )
     }
 
+    /// Layout a pagebreak inside of a container by breaking the container's
+    /// current region and skipping the remaining columns of the page.
+    fn layout_pagebreak(
+        &mut self,
+        engine: &mut Engine,
+        pagebreak: &Packed<PagebreakElem>,
+        styles: StyleChain,
+    ) -> SourceResult<()> {
+        // The container doesn't know the number of the page it is on.
+        if pagebreak.to(styles).is_some() {
+            bail!(
+                pagebreak.span(),
+                "pagebreaks to an even or odd page are not allowed inside of containers";
+                hint: "try moving the pagebreak out of the container"
+            );
+        }
+
+        let weak = pagebreak.weak(styles);
+        if self.regions.backlog.is_empty() && self.regions.last.is_none() {
+            if weak {
+                return Ok(());
+            }
+            bail!(
+                pagebreak.span(),
+                "pagebreaks are not allowed inside of containers";
+                hint: "only breakable blocks can contain pagebreaks"
+            );
+        }
+
+        if weak && !self.has_frames() {
+            return Ok(());
+        }
+
+        self.finish_region(engine, true)?;
+
+        // Leave the remaining columns of the page empty.
+        while self.regions.column != 0
+            && (!self.regions.backlog.is_empty() || self.regions.last.is_some())
+        {
+            self.finish_region(engine, true)?;
+        }
+
+        Ok(())
+    }
+
+    /// Layout a column break.
+    fn layout_colbreak(
+        &mut self,
+        engine: &mut Engine,
+        colbreak: &Packed<ColbreakElem>,
+        styles: StyleChain,
+    ) -> SourceResult<()> {
+        // Without further regions, there is no column to break to.
+        if self.regions.backlog.is_empty() && self.regions.last.is_none() {
+            return Ok(());
+        }
+
+        // A weak column break is skipped in a column without content, e.g.
+        // right after another column break.
+        if colbreak.weak(styles) && !self.has_frames() {
+            return Ok(());
+        }
+
+        self.finish_region(engine, true)
+    }
+
+    /// The distance from the last baseline in the current region to the
+    /// current position in the flow, if anything was laid out yet.
+    fn last_baseline_gap(&self) -> Option<Abs> {
+        let mut gap = Abs::zero();
+        for item in self.items.iter().rev() {
+            match item {
+                FlowItem::Absolute(v, _) => gap += *v,
+                FlowItem::Frame { frame, .. } if !item.is_out_of_flow() => {
+                    let baseline =
+                        frame.text_baselines().map_or(frame.height(), |(_, y)| y);
+                    return Some(gap + frame.height() - baseline);
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Whether any frames were laid out in the current region so far.
+    fn has_frames(&self) -> bool {
+        self.items.iter().any(|item| matches!(item, FlowItem::Frame { .. }))
+    }
+
     /// Layout a paragraph.
     fn layout_par(
         &mut self,
@@ -231,9 +349,19 @@ impl<'a> FlowLayouter<'a> {
         par: &Packed<ParElem>,
         styles: StyleChain,
     ) -> SourceResult<()> {
+        self.place_side_float(engine)?;
+
         let align = AlignElem::alignment_in(styles).resolve(styles);
         let leading = ParElem::leading_in(styles);
         let consecutive = self.last_was_par;
+        let exclusion = self.side_float.as_ref().and_then(|float| {
+            let height = self.regions.size.y - float.bottom;
+            (height > Abs::zero()).then_some(Exclusion {
+                side: float.align,
+                width: float.width,
+                height,
+            })
+        });
         let lines = par
             .layout(
                 engine,
@@ -241,6 +369,7 @@ impl<'a> FlowLayouter<'a> {
                 consecutive,
                 self.regions.base(),
                 self.regions.expand.x,
+                exclusion,
             )?
             .into_frames();
 
@@ -291,6 +420,9 @@ impl<'a> FlowLayouter<'a> {
         layoutable: &dyn LayoutSingle,
         styles: StyleChain,
     ) -> SourceResult<()> {
+        self.place_side_float(engine)?;
+        self.clear_side_float();
+
         let align = AlignElem::alignment_in(styles).resolve(styles);
         let sticky = BlockElem::sticky_in(styles);
         let pod = Regions::one(self.regions.base(), Axes::splat(false));
@@ -319,9 +451,18 @@ impl<'a> FlowLayouter<'a> {
             align.x().unwrap_or_default().resolve(styles)
         });
         let y_align = alignment.map(|align| align.y().map(|y| y.resolve(styles)));
+        let clip = placed.clip(styles);
         let mut frame = placed.layout(engine, styles, self.regions.base())?.into_frame();
         frame.meta(styles, false);
-        let item = FlowItem::Placed { frame, x_align, y_align, delta, float, clearance };
+        let item = FlowItem::Placed {
+            frame,
+            x_align,
+            y_align,
+            delta,
+            float,
+            clearance,
+            clip,
+        };
         self.layout_item(engine, item)
     }
 
@@ -339,6 +480,9 @@ impl<'a> FlowLayouter<'a> {
             self.regions.root = true;
         }
 
+        self.place_side_float(engine)?;
+        self.clear_side_float();
+
         let mut notes = Vec::new();
 
         if self.regions.is_full() {
@@ -358,7 +502,22 @@ impl<'a> FlowLayouter<'a> {
 
         // Layout the block itself.
         let sticky = BlockElem::sticky_in(styles);
-        let fragment = child.layout(engine, styles, self.regions)?;
+        let mut fragment = child.layout(engine, styles, self.regions)?;
+
+        // Keep the block's first baseline at least its lead away from the
+        // last baseline before it.
+        let lead = child.to_packed::<BlockElem>().and_then(|block| block.lead(styles));
+        if let (Some(lead), Some(gap), Some(first)) =
+            (lead, self.last_baseline_gap(), fragment.iter().next())
+        {
+            let baseline =
+                first.text_baselines().map_or(first.baseline(), |(top, _)| top);
+            let extra = lead - gap - baseline;
+            if extra > Abs::zero() {
+                self.layout_item(engine, FlowItem::Absolute(extra, false))?;
+                fragment = child.layout(engine, styles, self.regions)?;
+            }
+        }
 
         for (i, mut frame) in fragment.into_iter().enumerate() {
             // Find footnotes in the frame.
@@ -386,6 +545,74 @@ impl<'a> FlowLayouter<'a> {
         Ok(())
     }
 
+    /// Layout a block that floats at the side of the flow.
+    ///
+    /// The block is only placed in front of the next child, so that it is
+    /// top-aligned with the paragraph flowing beside it.
+    fn layout_side_float(
+        &mut self,
+        engine: &mut Engine,
+        block: &Packed<BlockElem>,
+        side: OuterHAlignment,
+        styles: StyleChain,
+    ) -> SourceResult<()> {
+        self.place_side_float(engine)?;
+        self.clear_side_float();
+
+        let pod = Regions::one(self.regions.base(), Axes::splat(false));
+        let mut frame = block.layout(engine, styles, pod)?.into_frame();
+        frame.meta(styles, false);
+
+        self.side_float = Some(SideFloat {
+            width: frame.width() + block.clearance(styles),
+            frame: Some(frame),
+            align: side.resolve(styles),
+            bottom: Abs::zero(),
+        });
+        self.last_was_par = false;
+        Ok(())
+    }
+
+    /// Place a pending side float at the current position in the flow.
+    fn place_side_float(&mut self, engine: &mut Engine) -> SourceResult<()> {
+        let Some(mut float) = self.side_float.take() else { return Ok(()) };
+        let Some(frame) = float.frame.take() else {
+            self.side_float = Some(float);
+            return Ok(());
+        };
+
+        let height = frame.height();
+        while !self.regions.size.y.fits(height) && !self.regions.in_last() {
+            self.finish_region(engine, false)?;
+        }
+
+        float.bottom = self.regions.size.y - height;
+        self.items.push(FlowItem::Placed {
+            frame,
+            x_align: float.align,
+            y_align: Smart::Auto,
+            delta: Axes::splat(Rel::zero()),
+            float: false,
+            clearance: Abs::zero(),
+            clip: false,
+        });
+        self.side_float = Some(float);
+        Ok(())
+    }
+
+    /// Move past a placed side float, so that the next child is laid out
+    /// below it.
+    fn clear_side_float(&mut self) {
+        if self.side_float.as_ref().is_some_and(|float| float.frame.is_none()) {
+            let float = self.side_float.take().unwrap();
+            let remaining = self.regions.size.y - float.bottom;
+            if remaining > Abs::zero() {
+                self.regions.size.y -= remaining;
+                self.items.push(FlowItem::Absolute(remaining, false));
+            }
+        }
+    }
+
     /// Layout a finished frame.
     fn layout_item(
         &mut self,
@@ -486,6 +713,10 @@ impl<'a> FlowLayouter<'a> {
     /// only (this is used to force the creation of a frame in case the
     /// remaining elements are all out-of-flow).
     fn finish_region(&mut self, engine: &mut Engine, force: bool) -> SourceResult<()> {
+        // Make sure that the region is large enough for a side float.
+        self.place_side_float(engine)?;
+        self.clear_side_float();
+
         if !force
             && !self.items.is_empty()
             && self.items.iter().all(FlowItem::is_out_of_flow)
@@ -550,6 +781,11 @@ impl<'a> FlowLayouter<'a> {
             size.y = self.initial.y;
         }
 
+        // The space that aligned content can be moved into. When unbreakable
+        // content overflows the region, it stays pinned to the top instead
+        // of being shifted out of the region's top edge.
+        let free = (size.y - used.y).max(Abs::zero());
+
         let mut output = Frame::soft(size);
         let mut ruler = FixedAlignment::Start;
         let mut float_top_offset = Abs::zero();
@@ -570,12 +806,14 @@ impl<'a> FlowLayouter<'a> {
                 FlowItem::Frame { frame, align, .. } => {
                     ruler = ruler.max(align.y);
                     let x = align.x.position(size.x - frame.width());
-                    let y = offset + ruler.position(size.y - used.y);
+                    let y = offset + ruler.position(free);
                     let pos = Point::new(x, y);
                     offset += frame.height();
                     output.push_frame(pos, frame);
                 }
-                FlowItem::Placed { frame, x_align, y_align, delta, float, .. } => {
+                FlowItem::Placed {
+                    frame, x_align, y_align, delta, float, clip, ..
+                } => {
                     let x = x_align.position(size.x - frame.width());
                     let y = if float {
                         match y_align {
@@ -597,14 +835,22 @@ impl<'a> FlowLayouter<'a> {
                             Smart::Custom(Some(align)) => {
                                 align.position(size.y - frame.height())
                             }
-                            _ => offset + ruler.position(size.y - used.y),
+                            _ => offset + ruler.position(free),
                         }
                     };
 
                     let pos = Point::new(x, y)
                         + delta.zip_map(size, Rel::relative_to).to_point();
 
-                    output.push_frame(pos, frame);
+                    if clip {
+                        // Cut off everything outside of the region.
+                        let mut clipped = Frame::soft(size);
+                        clipped.push_frame(pos, frame);
+                        clipped.clip(Path::rect(size));
+                        output.push_frame(Point::zero(), clipped);
+                    } else {
+                        output.push_frame(pos, frame);
+                    }
                 }
                 FlowItem::Footnote(frame) => {
                     let y = size.y - footnote_height + footnote_offset;
@@ -117,6 +117,31 @@ impl Frame {
         self.baseline = Some(baseline);
     }
 
+    /// The vertical positions of the first and last text baseline in the
+    /// frame, searching through untransformed groups.
+    pub fn text_baselines(&self) -> Option<(Abs, Abs)> {
+        fn collect(frame: &Frame, offset: Abs, range: &mut Option<(Abs, Abs)>) {
+            for (pos, item) in frame.items() {
+                let y = offset + pos.y;
+                match item {
+                    FrameItem::Group(group) if group.transform.is_identity() => {
+                        collect(&group.frame, y, range)
+                    }
+                    FrameItem::Text(_) => {
+                        let (first, last) = range.get_or_insert((y, y));
+                        *first = first.min(y);
+                        *last = last.max(y);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut range = None;
+        collect(self, Abs::zero(), &mut range);
+        range
+    }
+
     /// The distance from the baseline to the top of the frame.
     ///
     /// This is the same as `baseline()`, but more in line with the terminology
@@ -362,6 +387,31 @@ impl Frame {
         }
     }
 
+    /// Round the positions of all items as well as the sizes of shapes and
+    /// images to multiples of `unit`, such that they line up with a pixel
+    /// grid of that size.
+    ///
+    /// Transformed groups are positioned on the grid, but their contents are
+    /// left as is.
+    pub fn snap(&mut self, unit: Abs) {
+        let round = |v: Abs| unit * (v / unit).round();
+        for (pos, item) in Arc::make_mut(&mut self.items) {
+            *pos = pos.map(round);
+            match item {
+                FrameItem::Group(group) if group.transform.is_identity() => {
+                    group.frame.snap(unit);
+                }
+                FrameItem::Shape(shape, _) => match &mut shape.geometry {
+                    Geometry::Line(delta) => *delta = delta.map(round),
+                    Geometry::Rect(size) => *size = size.map(round),
+                    Geometry::Path(_) => {}
+                },
+                FrameItem::Image(_, size, _) => *size = size.map(round),
+                _ => {}
+            }
+        }
+    }
+
     /// Wrap the frame's contents in a group and modify that group with `f`.
     fn group<F>(&mut self, f: F)
     where
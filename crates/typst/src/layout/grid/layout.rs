@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::num::NonZeroUsize;
@@ -8,7 +10,7 @@ use ecow::eco_format;
 
 use super::lines::{
     generate_line_segments, hline_stroke_at_column, vline_stroke_at_row, Line,
-    LinePosition, LineSegment,
+    LinePosition, LineSegment, StrokePriority,
 };
 use super::rowspans::{Rowspan, UnbreakableRowGroup};
 use crate::diag::{
@@ -20,13 +22,16 @@ use crate::foundations::{
     Resolve, Smart, StyleChain, Value,
 };
 use crate::layout::{
-    Abs, Alignment, Axes, Dir, Fr, Fragment, Frame, FrameItem, LayoutMultiple, Length,
-    Point, Regions, Rel, Sides, Size, Sizing,
+    Abs, Alignment, Axes, Corner, Corners, Dir, FixedAlignment, Fr, Fragment, Frame,
+    FrameItem, GridBreak, GroupItem, LayoutMultiple, Length, Point, Regions, Rel,
+    RowOverflow, Sides, Size, Sizing,
 };
 use crate::syntax::Span;
 use crate::text::TextElem;
-use crate::util::{MaybeReverseIter, NonZeroExt, Numeric};
-use crate::visualize::{Geometry, Paint, Stroke};
+use crate::util::{hash128, Get, MaybeReverseIter, NonZeroExt, Numeric};
+use crate::visualize::{
+    clip_rect, Color, FixedStroke, Geometry, Paint, Path, Shape, Stroke,
+};
 
 /// A value that can be configured per cell.
 #[derive(Debug, Clone, PartialEq, Hash)]
@@ -193,6 +198,11 @@ pub struct Cell {
     /// By default, a cell spanning only fixed-size rows is unbreakable, while
     /// a cell spanning at least one `auto`-sized row is breakable.
     pub breakable: bool,
+    /// Whether the cell's content may extend into the following empty cells
+    /// of its row.
+    pub spill: bool,
+    /// Whether the cell's body is empty, so that content may spill into it.
+    pub empty: bool,
 }
 
 impl From<Content> for Cell {
@@ -206,6 +216,8 @@ impl From<Content> for Cell {
             stroke: Sides::splat(None),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            spill: false,
+            empty: false,
         }
     }
 }
@@ -335,6 +347,7 @@ pub trait ResolvableCell {
         self,
         x: usize,
         y: usize,
+        colspan: NonZeroUsize,
         fill: &Option<Paint>,
         align: Smart<Alignment>,
         inset: Sides<Option<Rel<Length>>>,
@@ -349,8 +362,9 @@ pub trait ResolvableCell {
     /// Returns this cell's row override.
     fn y(&self, styles: StyleChain) -> Smart<usize>;
 
-    /// The amount of columns spanned by this cell.
-    fn colspan(&self, styles: StyleChain) -> NonZeroUsize;
+    /// The amount of columns spanned by this cell, or `Auto` if it spans to
+    /// the end of its row.
+    fn colspan(&self, styles: StyleChain) -> Smart<NonZeroUsize>;
 
     /// The amount of rows spanned by this cell.
     fn rowspan(&self, styles: StyleChain) -> NonZeroUsize;
@@ -668,7 +682,10 @@ impl CellGrid {
                     ResolvableGridItem::Cell(cell) => cell,
                 };
                 let cell_span = cell.span();
-                let colspan = cell.colspan(styles).get();
+                // A cell spanning to the end of its row is positioned like a
+                // single-column cell.
+                let fill_row = cell.colspan(styles).is_auto();
+                let colspan = cell.colspan(styles).map_or(1, NonZeroUsize::get);
                 let rowspan = cell.rowspan(styles).get();
                 // Let's calculate the cell's final position based on its
                 // requested position.
@@ -689,6 +706,7 @@ impl CellGrid {
                 };
                 let x = resolved_index % c;
                 let y = resolved_index / c;
+                let colspan = if fill_row { c - x } else { colspan };
 
                 if colspan > c - x {
                     bail!(
@@ -717,6 +735,7 @@ impl CellGrid {
                 let cell = cell.resolve_cell(
                     x,
                     y,
+                    NonZeroUsize::new(colspan).unwrap_or(NonZeroUsize::ONE),
                     &fill.resolve(engine, styles, x, y)?,
                     align.resolve(engine, styles, x, y)?,
                     inset.resolve(engine, styles, x, y)?,
@@ -978,6 +997,7 @@ impl CellGrid {
                     let new_cell = T::default().resolve_cell(
                         x,
                         y,
+                        NonZeroUsize::ONE,
                         &fill.resolve(engine, styles, x, y)?,
                         align.resolve(engine, styles, x, y)?,
                         inset.resolve(engine, styles, x, y)?,
@@ -1155,6 +1175,30 @@ impl CellGrid {
         ))
     }
 
+    /// Replace the gutter row after the row with the given index with the
+    /// given gap. Does nothing if there is no gutter row after it.
+    pub fn set_row_gutter(&mut self, y: usize, gap: Rel<Length>) {
+        if !self.has_gutter {
+            return;
+        }
+        if let Some(row) = self.rows.get_mut(y.saturating_mul(2).saturating_add(1)) {
+            *row = Sizing::Rel(gap);
+        }
+    }
+
+    /// Replace the gutter row below the header with the given gap.
+    ///
+    /// The gutter row is part of the header's rows, unless the header spans
+    /// the whole grid, in which case there is nothing to replace.
+    pub fn set_header_gutter(&mut self, gap: Rel<Length>) {
+        let Some(end) = self.header.as_ref().map(|header| header.unwrap().end) else {
+            return;
+        };
+        if self.has_gutter && end > 0 && end % 2 == 0 {
+            self.rows[end - 1] = Sizing::Rel(gap);
+        }
+    }
+
     /// Generates the cell grid, given the tracks and resolved entries.
     pub(super) fn new_internal(
         tracks: Axes<&[Sizing]>,
@@ -1548,6 +1592,34 @@ pub struct GridLayouter<'a> {
     /// The simulated footer height for this region.
     /// The simulation occurs before any rows are laid out for a region.
     pub(super) footer_height: Abs,
+    /// Whether to draw an overlay of the resolved tracks.
+    pub(super) debug: bool,
+    /// Which cells to align on their decimal separator.
+    pub(super) decimal: Celled<bool>,
+    /// For each column, the offset from its start at which decimal-aligned
+    /// cells place their decimal separator.
+    pub(super) decimal_anchors: Vec<Option<Abs>>,
+    /// Whether each cell, in row-major order, is decimal-aligned.
+    pub(super) decimal_cells: Vec<bool>,
+    /// The maximum total height of an auto row.
+    pub(super) max_row_height: Option<Abs>,
+    /// What to do with the contents of auto rows that were capped.
+    pub(super) row_overflow: RowOverflow,
+    /// Where to continue when the grid breaks.
+    pub(super) break_to: GridBreak,
+    /// Whether cells spanning multiple auto columns grow all of them instead
+    /// of only the last one.
+    pub(super) distribute_spans: bool,
+    /// Whether empty auto rows don't get a gutter above them.
+    pub(super) collapse_empty_rows: bool,
+    /// The fixed width and height of the grid, if any.
+    pub(super) fixed: Axes<Smart<Abs>>,
+    /// How much to round the outer corners of each region of the grid.
+    pub(super) radius: Corners<Rel<Abs>>,
+    /// Cells measured during track sizing, keyed by the cell's position and
+    /// the hash of the regions it was measured in. Measuring a cell in
+    /// different regions thus never reuses a stale result.
+    pub(super) measurements: RefCell<HashMap<(Axes<usize>, u128), Fragment>>,
     /// The span of the grid element.
     pub(super) span: Span,
 }
@@ -1613,10 +1685,85 @@ impl<'a> GridLayouter<'a> {
             is_rtl: TextElem::dir_in(styles) == Dir::RTL,
             header_height: Abs::zero(),
             footer_height: Abs::zero(),
+            debug: false,
+            decimal: Celled::Value(false),
+            decimal_anchors: vec![],
+            decimal_cells: vec![],
+            max_row_height: None,
+            row_overflow: RowOverflow::Clip,
+            break_to: GridBreak::Column,
+            distribute_spans: false,
+            collapse_empty_rows: false,
+            fixed: Axes::splat(Smart::Auto),
+            radius: Corners::splat(Rel::zero()),
+            measurements: RefCell::new(HashMap::new()),
             span,
         }
     }
 
+    /// Give the grid a fixed width and height, as far as they are custom.
+    /// The tracks then resolve within that size instead of the regions.
+    pub fn with_size(mut self, width: Smart<Abs>, height: Smart<Abs>) -> Self {
+        if let Smart::Custom(width) = width {
+            self.regions.size.x = width;
+        }
+        if let Smart::Custom(height) = height {
+            let size = Size::new(self.regions.size.x, height);
+            self.regions = Regions::one(size, self.regions.expand);
+        }
+        self.initial = self.regions.size;
+        self.fixed = Axes::new(width, height);
+        self
+    }
+
+    /// Draw an overlay of the resolved tracks.
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Align the given cells on their decimal separator.
+    pub fn with_decimal(mut self, decimal: Celled<bool>) -> Self {
+        self.decimal = decimal;
+        self
+    }
+
+    /// Limit the total height of each auto row.
+    pub fn with_max_row_height(mut self, max_row_height: Option<Abs>) -> Self {
+        self.max_row_height = max_row_height;
+        self
+    }
+
+    /// Deal with the contents of capped auto rows in the given way.
+    pub fn with_row_overflow(mut self, row_overflow: RowOverflow) -> Self {
+        self.row_overflow = row_overflow;
+        self
+    }
+
+    /// Continue in the given kind of region when the grid breaks.
+    pub fn with_break_to(mut self, break_to: GridBreak) -> Self {
+        self.break_to = break_to;
+        self
+    }
+
+    /// Let cells spanning multiple auto columns grow all of them.
+    pub fn with_distribute_spans(mut self, distribute_spans: bool) -> Self {
+        self.distribute_spans = distribute_spans;
+        self
+    }
+
+    /// Drop the gutter above auto rows without any height.
+    pub fn with_collapse_empty_rows(mut self, collapse_empty_rows: bool) -> Self {
+        self.collapse_empty_rows = collapse_empty_rows;
+        self
+    }
+
+    /// Round the outer corners of each region of the grid.
+    pub fn with_radius(mut self, radius: Corners<Rel<Abs>>) -> Self {
+        self.radius = radius;
+        self
+    }
+
     /// Determines the columns sizes and then layouts the grid row-by-row.
     pub fn layout(mut self, engine: &mut Engine) -> SourceResult<Fragment> {
         self.measure_columns(engine)?;
@@ -1655,6 +1802,8 @@ impl<'a> GridLayouter<'a> {
             self.layout_row(y, engine)?;
         }
 
+        // No rows follow the last region, so there is nothing to skip to.
+        self.break_to = GridBreak::Column;
         self.finish_region(engine)?;
 
         // Layout any missing rowspans.
@@ -1672,7 +1821,25 @@ impl<'a> GridLayouter<'a> {
             self.layout_rowspan(rowspan, None, engine)?;
         }
 
-        self.render_fills_strokes()
+        let fixed = self.fixed;
+        let align = Axes::new(
+            if self.is_rtl { FixedAlignment::End } else { FixedAlignment::Start },
+            FixedAlignment::Start,
+        );
+        let mut fragment = self.render_fills_strokes()?;
+
+        // Fill the fixed size even if the tracks don't.
+        if fixed.x.is_custom() || fixed.y.is_custom() {
+            for frame in fragment.iter_mut() {
+                let target = Size::new(
+                    fixed.x.unwrap_or(frame.width()),
+                    fixed.y.unwrap_or(frame.height()),
+                );
+                frame.resize(target, align);
+            }
+        }
+
+        Ok(fragment)
     }
 
     /// Layout the given row.
@@ -2149,6 +2316,29 @@ impl<'a> GridLayouter<'a> {
                 dx += col;
             }
 
+            // With rounded corners, the fills are clipped to the rounded outer
+            // rectangle and the outer border is rounded to match. Inner lines
+            // stay square.
+            let size = frame.size();
+            let max_radius = size.x.min(size.y) / 2.0;
+            let radius =
+                self.radius.map(|r| r.relative_to(max_radius * 2.0).min(max_radius));
+            if radius.iter().any(|r| *r > Abs::zero()) {
+                round_border_corners(&mut lines, size, radius, self.span);
+
+                let mut background = Frame::soft(size);
+                for (pos, item) in fills {
+                    background.push(pos, item);
+                }
+                background.clip(clip_rect(
+                    size,
+                    radius.map(Rel::from),
+                    &Sides::splat(None),
+                ));
+                fills =
+                    vec![(Point::zero(), FrameItem::Group(GroupItem::new(background)))];
+            }
+
             // Now we render each fill and stroke by prepending to the frame,
             // such that both appear below cell contents. Fills come first so
             // that they appear below lines.
@@ -2157,11 +2347,46 @@ impl<'a> GridLayouter<'a> {
                     .into_iter()
                     .chain(lines.into_iter().map(|(_, _, point, shape)| (point, shape))),
             );
+
+            if self.debug {
+                self.render_debug_overlay(frame, rows);
+            }
         }
 
         Ok(Fragment::frames(finished))
     }
 
+    /// Draws the resolved column and row sizes of a region on top of its
+    /// frame. Content tracks are outlined and gutter tracks are shaded.
+    fn render_debug_overlay(&self, frame: &mut Frame, rows: &[RowPiece]) {
+        let outline = FixedStroke::from_pair(Color::RED.with_alpha(0.5), Abs::pt(0.5));
+        let shade: Paint = Color::BLUE.with_alpha(0.15).into();
+        let track = |index: usize, size: Size| {
+            let geometry = Geometry::Rect(size);
+            let shape = if self.grid.is_gutter_track(index) {
+                geometry.filled(shade.clone())
+            } else {
+                geometry.stroked(outline.clone())
+            };
+            FrameItem::Shape(shape, self.span)
+        };
+
+        let height: Abs = rows.iter().map(|row| row.height).sum();
+        let mut dx = Abs::zero();
+        for (x, &col) in self.rcols.iter().enumerate().rev_if(self.is_rtl) {
+            let size = Size::new(col, height);
+            frame.push(Point::with_x(dx), track(x, size));
+            dx += col;
+        }
+
+        let mut dy = Abs::zero();
+        for row in rows {
+            let size = Size::new(self.width, row.height);
+            frame.push(Point::with_y(dy), track(row.y, size));
+            dy += row.height;
+        }
+    }
+
     /// Determine all column sizes.
     fn measure_columns(&mut self, engine: &mut Engine) -> SourceResult<()> {
         // Sum of sizes of resolved relative tracks.
@@ -2188,8 +2413,10 @@ impl<'a> GridLayouter<'a> {
         // Size that is not used by fixed-size columns.
         let available = self.regions.size.x - rel;
         if available >= Abs::zero() {
-            // Determine size of auto columns.
+            // Determine size of auto columns. They grow to fit their
+            // decimal-aligned cells before the remaining space is known.
             let (auto, count) = self.measure_auto_columns(engine, available)?;
+            let auto = auto + self.measure_decimal_columns(engine, true)?;
 
             // If there is remaining space, distribute it to fractional columns,
             // otherwise shrink auto columns.
@@ -2199,14 +2426,102 @@ impl<'a> GridLayouter<'a> {
             } else {
                 self.shrink_auto_columns(available, count);
             }
+        } else {
+            self.measure_decimal_columns(engine, true)?;
         }
 
+        // All other columns have their final size now.
+        self.measure_decimal_columns(engine, false)?;
+
         // Sum up the resolved column sizes once here.
         self.width = self.rcols.iter().sum();
 
         Ok(())
     }
 
+    /// Determine where the decimal-aligned cells of either the auto columns or
+    /// all other columns place their decimal separator. Auto columns grow to
+    /// fit the shifted cells. Returns by how much they grew in total.
+    fn measure_decimal_columns(
+        &mut self,
+        engine: &mut Engine,
+        auto: bool,
+    ) -> SourceResult<Abs> {
+        let mut grown = Abs::zero();
+        if self.decimal == Celled::Value(false) {
+            return Ok(grown);
+        }
+
+        let cols = self.grid.cols.len();
+        if self.decimal_anchors.is_empty() {
+            self.decimal_anchors = vec![None; cols];
+            self.decimal_cells = vec![false; cols * self.grid.rows.len()];
+        }
+
+        for x in 0..cols {
+            if (self.grid.cols[x] == Sizing::Auto) != auto {
+                continue;
+            }
+
+            // The largest distance from a cell's start to its separator and
+            // from its separator to its end.
+            let mut before = Abs::zero();
+            let mut after = Abs::zero();
+            let mut any = false;
+
+            for y in 0..self.grid.rows.len() {
+                let Some(cell) = self.grid.cell(x, y) else { continue };
+                if cell.colspan.get() > 1 || cell.rowspan.get() > 1 {
+                    continue;
+                }
+
+                // The user sees positions without gutter tracks.
+                let (cx, cy) = if self.grid.has_gutter { (x / 2, y / 2) } else { (x, y) };
+                if !self.decimal.resolve(engine, self.styles, cx, cy)? {
+                    continue;
+                }
+
+                let size = Size::new(self.rcols[x], self.regions.base().y);
+                let pod = Regions::one(size, Axes::splat(false));
+                let frame =
+                    self.measure_cell(engine, cell, Axes::new(x, y), pod)?.into_frame();
+                let Some(anchor) = decimal_anchor(&frame) else { continue };
+
+                before.set_max(anchor);
+                after.set_max(frame.width() - anchor);
+                self.decimal_cells[y * cols + x] = true;
+                any = true;
+            }
+
+            if any {
+                if auto && self.rcols[x] < before + after {
+                    grown += before + after - self.rcols[x];
+                    self.rcols[x] = before + after;
+                }
+                self.decimal_anchors[x] = Some(before);
+            }
+        }
+
+        Ok(grown)
+    }
+
+    /// Shift the contents of a decimal-aligned cell such that its decimal
+    /// separator is at the column's anchor.
+    fn align_decimal(&self, x: usize, y: usize, frame: &mut Frame) {
+        let Some(anchor) = self.decimal_anchors.get(x).copied().flatten() else {
+            return;
+        };
+
+        let index = y * self.grid.cols.len() + x;
+        if !self.decimal_cells.get(index).copied().unwrap_or(false) {
+            return;
+        }
+
+        if let Some(current) = decimal_anchor(frame) {
+            frame.translate(Point::with_x(anchor - current));
+        }
+    }
+
     /// Total width spanned by the cell (among resolved columns).
     /// Includes spanned gutter columns.
     pub(super) fn cell_spanned_width(&self, cell: &Cell, x: usize) -> Abs {
@@ -2214,14 +2529,54 @@ impl<'a> GridLayouter<'a> {
         self.rcols.iter().skip(x).take(colspan).sum()
     }
 
+    /// Width available to the content of the cell at the given position.
+    ///
+    /// This is the width spanned by the cell, extended across the empty cells
+    /// (and the gutters before them) that follow it in its row if its content
+    /// may spill.
+    pub(super) fn cell_content_width(&self, cell: &Cell, x: usize, y: usize) -> Abs {
+        let mut width = self.cell_spanned_width(cell, x);
+        if !cell.spill || cell.rowspan.get() > 1 {
+            return width;
+        }
+
+        let mut gutter = Abs::zero();
+        let mut next = x + self.grid.effective_colspan_of_cell(cell);
+        while next < self.rcols.len() {
+            if self.grid.is_gutter_track(next) {
+                gutter += self.rcols[next];
+                next += 1;
+                continue;
+            }
+
+            match self.grid.cell(next, y) {
+                Some(neighbor) if neighbor.empty && neighbor.rowspan.get() == 1 => {
+                    width += gutter + self.cell_spanned_width(neighbor, next);
+                    gutter = Abs::zero();
+                    next += self.grid.effective_colspan_of_cell(neighbor);
+                }
+                _ => break,
+            }
+        }
+
+        width
+    }
+
     /// Measure the size that is available to auto columns.
+    ///
+    /// By default, a cell spanning multiple columns only affects the last
+    /// auto column it spans. With `distribute_spans`, auto columns are first
+    /// sized to fit the cells that span only them, like in CSS Grid.
+    /// Afterwards, cells spanning multiple columns are considered in the
+    /// order of increasing colspan: If such a cell needs more width than its
+    /// spanned columns (and gutters) already provide, the spanned auto
+    /// columns grow proportionally to their widths to fit it. This ensures
+    /// that a wide colspan doesn't blow up a single column.
     fn measure_auto_columns(
         &mut self,
         engine: &mut Engine,
         available: Abs,
     ) -> SourceResult<(Abs, usize)> {
-        let mut auto = Abs::zero();
-        let mut count = 0;
         let all_frac_cols = self
             .grid
             .cols
@@ -2231,6 +2586,10 @@ impl<'a> GridLayouter<'a> {
             .map(|(x, _)| x)
             .collect::<Vec<_>>();
 
+        // Cells spanning multiple columns, which are considered once all
+        // columns are sized from the cells spanning only them.
+        let mut spanning = vec![];
+
         // Determine size of auto columns by laying out all cells in those
         // columns, measuring them and finding the largest one.
         for (x, &col) in self.grid.cols.iter().enumerate() {
@@ -2250,6 +2609,25 @@ impl<'a> GridLayouter<'a> {
                 }
                 let cell = self.grid.cell(parent.x, parent.y).unwrap();
                 let colspan = self.grid.effective_colspan_of_cell(cell);
+                if colspan > 1 && self.distribute_spans {
+                    // Visit each spanning cell only once, from the first auto
+                    // column it spans.
+                    let first_spanned_auto_col = self
+                        .grid
+                        .cols
+                        .iter()
+                        .enumerate()
+                        .skip(parent.x)
+                        .take(colspan)
+                        .find(|(_, col)| **col == Sizing::Auto)
+                        .map(|(x, _)| x);
+
+                    if first_spanned_auto_col == Some(x) {
+                        spanning.push((colspan, parent));
+                    }
+                    continue;
+                }
+
                 if colspan > 1 {
                     let last_spanned_auto_col = self
                         .grid
@@ -2267,48 +2645,16 @@ impl<'a> GridLayouter<'a> {
                         // auto column.
                         continue;
                     }
-                }
 
-                if colspan > 1
-                    && self.regions.size.x.is_finite()
-                    && !all_frac_cols.is_empty()
-                    && all_frac_cols
-                        .iter()
-                        .all(|x| (parent.x..parent.x + colspan).contains(x))
-                {
-                    // Additionally, as a heuristic, a colspan won't affect the
-                    // size of auto columns if it already spans all fractional
-                    // columns, since those would already expand to provide all
-                    // remaining available after auto column sizing to that
-                    // cell. However, this heuristic is only valid in finite
-                    // regions (pages without 'auto' width), since otherwise
-                    // the fractional columns don't expand at all.
-                    continue;
+                    if self.spans_all_frac_cols(&all_frac_cols, parent.x, colspan) {
+                        continue;
+                    }
                 }
 
-                // Sum the heights of spanned rows to find the expected
-                // available height for the cell, unless it spans a fractional
-                // or auto column.
-                let rowspan = self.grid.effective_rowspan_of_cell(cell);
-                let height = self
-                    .grid
-                    .rows
-                    .iter()
-                    .skip(y)
-                    .take(rowspan)
-                    .try_fold(Abs::zero(), |acc, col| {
-                        // For relative rows, we can already resolve the correct
-                        // base and for auto and fr we could only guess anyway.
-                        match col {
-                            Sizing::Rel(v) => Some(
-                                acc + v
-                                    .resolve(self.styles)
-                                    .relative_to(self.regions.base().y),
-                            ),
-                            _ => None,
-                        }
-                    })
-                    .unwrap_or_else(|| self.regions.base().y);
+                let height = self.expected_height_of_cell(cell, y);
+                let size = Size::new(available, height);
+                let pod = Regions::one(size, Axes::splat(false));
+                let frame = self.measure_cell(engine, cell, parent, pod)?.into_frame();
 
                 // Don't expand this auto column more than the cell actually
                 // needs. To do this, we check how much the other, previously
@@ -2318,28 +2664,124 @@ impl<'a> GridLayouter<'a> {
                 // that, since this is the last auto column spanned by this
                 // cell, all other auto columns will already have been resolved
                 // and will be considered.
-                // Only fractional columns will be excluded from this
-                // calculation, which can lead to auto columns being expanded
-                // unnecessarily when cells span both a fractional column and
-                // an auto column. One mitigation for this is the heuristic
-                // used above to not expand the last auto column spanned by a
-                // cell if it spans all fractional columns in a finite region.
                 let already_covered_width = self.cell_spanned_width(cell, parent.x);
-
-                let size = Size::new(available, height);
-                let pod = Regions::one(size, Axes::splat(false));
-                let frame = cell.measure(engine, self.styles, pod)?.into_frame();
                 resolved.set_max(frame.width() - already_covered_width);
             }
 
             self.rcols[x] = resolved;
-            auto += resolved;
-            count += 1;
+        }
+
+        // Now grow the spanned auto columns to fit cells spanning multiple
+        // columns, starting with the narrowest spans.
+        spanning.sort_by_key(|&(colspan, _)| colspan);
+        for (colspan, parent) in spanning {
+            if self.spans_all_frac_cols(&all_frac_cols, parent.x, colspan) {
+                continue;
+            }
+
+            let cell = self.grid.cell(parent.x, parent.y).unwrap();
+            let height = self.expected_height_of_cell(cell, parent.y);
+            let size = Size::new(available, height);
+            let pod = Regions::one(size, Axes::splat(false));
+            let frame = self.measure_cell(engine, cell, parent, pod)?.into_frame();
+
+            // Only fractional columns, which aren't resolved yet, are
+            // excluded from the width already provided to the cell. This can
+            // lead to auto columns being expanded unnecessarily when cells
+            // span both a fractional column and an auto column.
+            let excess = frame.width() - self.cell_spanned_width(cell, parent.x);
+            if excess <= Abs::zero() {
+                continue;
+            }
+
+            let autos: Vec<usize> = (parent.x..parent.x + colspan)
+                .filter(|&x| self.grid.cols[x] == Sizing::Auto)
+                .collect();
+            let total: Abs = autos.iter().map(|&x| self.rcols[x]).sum();
+            for &x in &autos {
+                let share = if total > Abs::zero() {
+                    self.rcols[x] / total
+                } else {
+                    1.0 / autos.len() as f64
+                };
+                self.rcols[x] += excess * share;
+            }
+        }
+
+        let mut auto = Abs::zero();
+        let mut count = 0;
+        for (x, &col) in self.grid.cols.iter().enumerate() {
+            if col == Sizing::Auto {
+                auto += self.rcols[x];
+                count += 1;
+            }
         }
 
         Ok((auto, count))
     }
 
+    /// Whether a colspan starting at `x` spans all fractional columns in a
+    /// finite region.
+    ///
+    /// As a heuristic, such a colspan won't affect the size of auto columns,
+    /// since the fractional columns would already expand to provide all
+    /// remaining available width after auto column sizing to that cell.
+    /// However, this heuristic is only valid in finite regions (pages without
+    /// 'auto' width), since otherwise the fractional columns don't expand at
+    /// all.
+    fn spans_all_frac_cols(
+        &self,
+        all_frac_cols: &[usize],
+        x: usize,
+        colspan: usize,
+    ) -> bool {
+        self.regions.size.x.is_finite()
+            && !all_frac_cols.is_empty()
+            && all_frac_cols.iter().all(|fx| (x..x + colspan).contains(fx))
+    }
+
+    /// The height available to a cell when measuring its width: The sum of
+    /// the heights of its spanned rows, unless it spans a fractional or auto
+    /// row, in which case this is the height of the region.
+    fn expected_height_of_cell(&self, cell: &Cell, y: usize) -> Abs {
+        let rowspan = self.grid.effective_rowspan_of_cell(cell);
+        self.grid
+            .rows
+            .iter()
+            .skip(y)
+            .take(rowspan)
+            .try_fold(Abs::zero(), |acc, row| {
+                // For relative rows, we can already resolve the correct base
+                // and for auto and fr we could only guess anyway.
+                match row {
+                    Sizing::Rel(v) => Some(
+                        acc + v.resolve(self.styles).relative_to(self.regions.base().y),
+                    ),
+                    _ => None,
+                }
+            })
+            .unwrap_or_else(|| self.regions.base().y)
+    }
+
+    /// Measure a cell, reusing an earlier measurement of the same cell in the
+    /// same regions if there is one.
+    fn measure_cell(
+        &self,
+        engine: &mut Engine,
+        cell: &Cell,
+        pos: Axes<usize>,
+        pod: Regions,
+    ) -> SourceResult<Fragment> {
+        let key = (pos, hash128(&pod));
+        if let Some(fragment) = self.measurements.borrow().get(&key) {
+            return Ok(fragment.clone());
+        }
+
+        let fragment = cell.measure(engine, self.styles, pod)?;
+        self.measurements.borrow_mut().insert(key, fragment.clone());
+        Ok(fragment)
+    }
+
     /// Distribute remaining space to fractional columns.
     fn grow_fractional_columns(&mut self, remaining: Abs, fr: Fr) {
         if fr.is_zero() {
@@ -2411,9 +2853,30 @@ impl<'a> GridLayouter<'a> {
             return Ok(());
         }
 
+        let split =
+            self.row_overflow == RowOverflow::Break && self.split_auto_row(&mut resolved);
+        let clip = !split
+            && self.cap_auto_row(&mut resolved)
+            && self.row_overflow != RowOverflow::Visible;
+
         // Layout into a single region.
         if let &[first] = resolved.as_slice() {
-            let frame = self.layout_single_row(engine, first, y)?;
+            // If enabled, a row without any height doesn't get its own
+            // gutter, so that the rows around it are only separated by a
+            // single gutter.
+            if self.collapse_empty_rows && first.is_zero() && self.is_collapsible_row(y) {
+                if let Some(Row::Frame(gutter, gy, _)) = self.lrows.last() {
+                    if *gy + 1 == y && self.grid.is_gutter_track(*gy) {
+                        self.regions.size.y += gutter.height();
+                        self.lrows.pop();
+                    }
+                }
+            }
+
+            let mut frame = self.layout_single_row(engine, first, y)?;
+            if clip {
+                frame.clip(Path::rect(frame.size()));
+            }
             self.push_row(frame, y, true);
 
             if self
@@ -2450,12 +2913,20 @@ impl<'a> GridLayouter<'a> {
                         Abs::zero()
                     },
             );
+
+            // A split row must not exceed its maximum height in any region.
+            if let Some(max) = self.max_row_height.filter(|_| split) {
+                target.set_min(max);
+            }
         }
 
         // Layout into multiple regions.
         let fragment = self.layout_multi_row(engine, &resolved, y)?;
         let len = fragment.len();
-        for (i, frame) in fragment.into_iter().enumerate() {
+        for (i, mut frame) in fragment.into_iter().enumerate() {
+            if clip {
+                frame.clip(Path::rect(frame.size()));
+            }
             self.push_row(frame, y, i + 1 == len);
             if i + 1 < len {
                 self.finish_region(engine)?;
@@ -2465,6 +2936,82 @@ impl<'a> GridLayouter<'a> {
         Ok(())
     }
 
+    /// Whether the gutter above a row may be dropped when the row is empty.
+    /// This is the case for body rows whose cells all lie within the row.
+    fn is_collapsible_row(&self, y: usize) -> bool {
+        let in_header = self
+            .grid
+            .header
+            .as_ref()
+            .and_then(Repeatable::as_repeated)
+            .is_some_and(|header| y < header.end);
+
+        !in_header
+            && (0..self.grid.cols.len()).all(|x| {
+                self.grid.is_gutter_track(x)
+                    || self.grid.parent_cell_position(x, y).is_some_and(|parent| {
+                        parent.y == y
+                            && self
+                                .grid
+                                .cell(parent.x, parent.y)
+                                .is_some_and(|cell| cell.rowspan.get() == 1)
+                    })
+            })
+    }
+
+    /// Limit the total height of an auto row's regions to the maximum row
+    /// height, dropping regions that are no longer needed. Returns whether
+    /// the row was shortened.
+    fn cap_auto_row(&self, resolved: &mut Vec<Abs>) -> bool {
+        let Some(max) = self.max_row_height else { return false };
+
+        let mut remaining = max;
+        let Some(i) = resolved.iter().position(|&height| {
+            let exceeds = !remaining.fits(height);
+            if !exceeds {
+                remaining -= height;
+            }
+            exceeds
+        }) else {
+            return false;
+        };
+
+        resolved[i] = remaining.max(Abs::zero());
+        resolved.truncate(i + 1);
+        true
+    }
+
+    /// Split the regions of an auto row such that none of them exceeds the
+    /// maximum row height, so that the row continues in the following
+    /// regions. Returns whether the row was split, which is only possible in
+    /// breakable rows and when there are enough regions to split into.
+    fn split_auto_row(&self, resolved: &mut Vec<Abs>) -> bool {
+        let Some(max) = self.max_row_height else { return false };
+        if self.unbreakable_rows_left > 0
+            || max <= Abs::zero()
+            || resolved.iter().all(|&height| max.fits(height))
+        {
+            return false;
+        }
+
+        let mut pieces = vec![];
+        for &height in resolved.iter() {
+            let mut height = height;
+            while !max.fits(height) {
+                pieces.push(max);
+                height -= max;
+            }
+            pieces.push(height);
+        }
+
+        if self.regions.last.is_none() && pieces.len() > 1 + self.regions.backlog.len() {
+            return false;
+        }
+
+        *resolved = pieces;
+        true
+    }
+
     /// Measure the regions sizes of an auto row. The option is always `Some(_)`
     /// if `can_skip` is false.
     /// If `unbreakable_rows_left` is positive, this function shall only return
@@ -2556,7 +3103,7 @@ impl<'a> GridLayouter<'a> {
                 pod
             };
 
-            let frames = cell.measure(engine, self.styles, pod)?.into_frames();
+            let frames = self.measure_cell(engine, cell, parent, pod)?.into_frames();
 
             // Skip the first region if one cell in it is empty. Then,
             // remeasure.
@@ -2704,7 +3251,7 @@ impl<'a> GridLayouter<'a> {
             if let Some(cell) = self.grid.cell(x, y) {
                 // Rowspans have a separate layout step
                 if cell.rowspan.get() == 1 {
-                    let width = self.cell_spanned_width(cell, x);
+                    let width = self.cell_content_width(cell, x, y);
                     let size = Size::new(width, height);
                     let mut pod = Regions::one(size, Axes::splat(true));
                     if self.grid.rows[y] == Sizing::Auto
@@ -2715,7 +3262,8 @@ impl<'a> GridLayouter<'a> {
                         // rows.
                         pod.full = self.regions.full;
                     }
-                    let frame = cell.layout(engine, self.styles, pod)?.into_frame();
+                    let mut frame = cell.layout(engine, self.styles, pod)?.into_frame();
+                    self.align_decimal(x, y, &mut frame);
                     let mut pos = pos;
                     if self.is_rtl {
                         // In the grid, cell colspans expand to the right,
@@ -2765,12 +3313,13 @@ impl<'a> GridLayouter<'a> {
             if let Some(cell) = self.grid.cell(x, y) {
                 // Rowspans have a separate layout step
                 if cell.rowspan.get() == 1 {
-                    let width = self.cell_spanned_width(cell, x);
+                    let width = self.cell_content_width(cell, x, y);
                     pod.size.x = width;
 
                     // Push the layouted frames into the individual output frames.
                     let fragment = cell.layout(engine, self.styles, pod)?;
-                    for (output, frame) in outputs.iter_mut().zip(fragment) {
+                    for (output, mut frame) in outputs.iter_mut().zip(fragment) {
+                        self.align_decimal(x, y, &mut frame);
                         let mut pos = pos;
                         if self.is_rtl {
                             let offset = -width + rcol;
@@ -3003,6 +3552,18 @@ impl<'a> GridLayouter<'a> {
         self.finished.push(output);
         self.rrows.push(resolved_rows);
         self.regions.next();
+
+        // Leave the remaining columns of the parent region empty.
+        if self.break_to == GridBreak::Region {
+            while self.regions.column != 0
+                && (!self.regions.backlog.is_empty() || self.regions.last.is_some())
+            {
+                self.finished.push(Frame::soft(Size::new(self.width, Abs::zero())));
+                self.rrows.push(vec![]);
+                self.regions.next();
+            }
+        }
+
         self.initial = self.regions.size;
     }
 
@@ -3139,6 +3700,125 @@ impl<'a> GridLayouter<'a> {
     }
 }
 
+/// Rounds the outer corners of a grid region's border.
+///
+/// Border lines reaching a rounded corner are shortened by the corner's radius
+/// and the thickest of them is continued around the corner with a quarter
+/// circle.
+fn round_border_corners(
+    lines: &mut Vec<(Abs, StrokePriority, Point, FrameItem)>,
+    size: Size,
+    radius: Corners<Abs>,
+    span: Span,
+) {
+    // The stroke to draw each corner's arc with, if any line reaches it.
+    let mut corner_strokes: Corners<Option<(Abs, StrokePriority, FixedStroke)>> =
+        Corners::splat(None);
+
+    lines.retain_mut(|(thickness, priority, pos, item)| {
+        let FrameItem::Shape(Shape { geometry: Geometry::Line(target), stroke, .. }, _) =
+            item
+        else {
+            return true;
+        };
+
+        let half = *thickness / 2.0;
+        let vertical = target.x.is_zero();
+        let (offset, extent) = if vertical { (pos.x, size.x) } else { (pos.y, size.y) };
+        let (start_corner, end_corner) = match (vertical, offset) {
+            (true, x) if x.approx_eq(Abs::zero()) => {
+                (Corner::TopLeft, Corner::BottomLeft)
+            }
+            (true, x) if x.approx_eq(extent) => (Corner::TopRight, Corner::BottomRight),
+            (false, y) if y.approx_eq(Abs::zero()) => (Corner::TopLeft, Corner::TopRight),
+            (false, y) if y.approx_eq(extent) => {
+                (Corner::BottomLeft, Corner::BottomRight)
+            }
+            _ => return true,
+        };
+
+        // The line's start and end along its direction, without the
+        // extension by half its thickness used to join square corners.
+        let length = if vertical { size.y } else { size.x };
+        let (mut start, mut end) = if vertical {
+            (pos.y + half, pos.y + target.y - half)
+        } else {
+            (pos.x + half, pos.x + target.x - half)
+        };
+
+        let mut start_ext = half;
+        let mut end_ext = half;
+        let mut record = |corner: Corner| {
+            let slot = corner_strokes.get_mut(corner);
+            if slot
+                .as_ref()
+                .map_or(true, |(t, p, _)| (*t, *p) < (*thickness, *priority))
+            {
+                *slot = stroke.clone().map(|stroke| (*thickness, *priority, stroke));
+            }
+        };
+
+        let r = radius.get(start_corner);
+        if r > Abs::zero() && start.approx_eq(Abs::zero()) {
+            start = r;
+            start_ext = Abs::zero();
+            record(start_corner);
+        }
+
+        let r = radius.get(end_corner);
+        if r > Abs::zero() && end.approx_eq(length) {
+            end = length - r;
+            end_ext = Abs::zero();
+            record(end_corner);
+        }
+
+        if end <= start {
+            return false;
+        }
+
+        let total = end - start + start_ext + end_ext;
+        if vertical {
+            pos.y = start - start_ext;
+            *target = Point::with_y(total);
+        } else {
+            pos.x = start - start_ext;
+            *target = Point::with_x(total);
+        }
+
+        true
+    });
+
+    // Connect the shortened lines with quarter circles, approximated by cubic
+    // Bézier curves.
+    let k = 0.551784;
+    for (corner, sx, sy) in [
+        (Corner::TopLeft, 1.0, 1.0),
+        (Corner::TopRight, -1.0, 1.0),
+        (Corner::BottomRight, -1.0, -1.0),
+        (Corner::BottomLeft, 1.0, -1.0),
+    ] {
+        let r = radius.get(corner);
+        let Some((thickness, priority, stroke)) = corner_strokes.get_ref(corner).clone()
+        else {
+            continue;
+        };
+
+        let origin = Point::new(
+            if sx > 0.0 { Abs::zero() } else { size.x },
+            if sy > 0.0 { Abs::zero() } else { size.y },
+        );
+        let point = |x: f64, y: f64| origin + Point::new(r * x * sx, r * y * sy);
+
+        let mut path = Path::new();
+        path.move_to(point(0.0, 1.0));
+        path.cubic_to(point(0.0, 1.0 - k), point(1.0 - k, 0.0), point(1.0, 0.0));
+        let arc = Geometry::Path(path).stroked(stroke);
+        lines.push((thickness, priority, Point::zero(), FrameItem::Shape(arc, span)));
+    }
+
+    lines.sort_by_key(|(thickness, priority, ..)| (*thickness, *priority));
+}
+
 /// Turn an iterator of extents into an iterator of offsets before, in between,
 /// and after the extents, e.g. [10mm, 5mm] -> [0mm, 10mm, 15mm].
 pub(super) fn points(
@@ -3160,3 +3840,36 @@ pub(super) fn in_last_with_offset(regions: Regions<'_>, offset: Abs) -> bool {
     regions.backlog.is_empty()
         && regions.last.map_or(true, |height| regions.size.y + offset == height)
 }
+
+/// Find the horizontal position of the first decimal separator in a frame's
+/// text. If there is none, returns the position where its text ends instead.
+fn decimal_anchor(frame: &Frame) -> Option<Abs> {
+    fn search(frame: &Frame, offset: Abs, end: &mut Option<Abs>) -> Option<Abs> {
+        for (pos, item) in frame.items() {
+            let x = offset + pos.x;
+            match item {
+                FrameItem::Group(group) => {
+                    let found = search(&group.frame, x + group.transform.tx, end);
+                    if found.is_some() {
+                        return found;
+                    }
+                }
+                FrameItem::Text(text) => {
+                    let mut x = x;
+                    for glyph in &text.glyphs {
+                        if text.text.as_str()[glyph.range()].starts_with('.') {
+                            return Some(x);
+                        }
+                        x += glyph.x_advance.at(text.size);
+                    }
+                    *end = Some(end.map_or(x, |end| end.max(x)));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    let mut end = None;
+    search(frame, Abs::zero(), &mut end).or(end)
+}
@@ -612,6 +612,8 @@ mod test {
             stroke: Sides::splat(Some(Arc::new(Stroke::default()))),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            spill: false,
+            empty: false,
         }
     }
 
@@ -624,6 +626,8 @@ mod test {
             stroke: Sides::splat(Some(Arc::new(Stroke::default()))),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            spill: false,
+            empty: false,
         }
     }
 
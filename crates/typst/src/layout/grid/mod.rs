@@ -8,26 +8,29 @@ pub use self::layout::{
 };
 pub use self::lines::LinePosition;
 
+use std::borrow::Cow;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use ecow::{eco_format, EcoString};
 use smallvec::{smallvec, SmallVec};
 
-use crate::diag::{bail, SourceResult, StrResult, Trace, Tracepoint};
+use crate::diag::{bail, warning, SourceResult, StrResult, Trace, Tracepoint};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Array, Content, Fold, Packed, Show, Smart, StyleChain, Value,
+    array, cast, dict, elem, scope, Args, Array, Cast, Construct, Content, Context, Dict,
+    Fold, FromValue, Func, Packed, Resolve, Show, Smart, StyleChain, Value,
 };
 use crate::layout::{
-    Abs, AlignElem, Alignment, Axes, Dir, Fragment, LayoutMultiple, Length,
-    OuterHAlignment, OuterVAlignment, Regions, Rel, Sides, Sizing,
+    Abs, AlignElem, Alignment, Angle, Axes, Corners, Dir, Fr, Fragment, Frame,
+    LayoutMultiple, Length, OuterHAlignment, OuterVAlignment, Ratio, Regions, Rel,
+    RotateElem, ScaleElem, Sides, Size, Sizing, Transform,
 };
 use crate::model::{TableCell, TableFooter, TableHLine, TableHeader, TableVLine};
 use crate::syntax::Span;
 use crate::text::TextElem;
-use crate::util::NonZeroExt;
-use crate::visualize::{Paint, Stroke};
+use crate::util::{NonZeroExt, Numeric};
+use crate::visualize::{Paint, Path, Stroke};
 
 /// Arranges content in a grid.
 ///
@@ -63,7 +66,10 @@ use crate::visualize::{Paint, Stroke};
 ///   the remaining space will be divided among the fractional tracks according
 ///   to their fractions. For example, if there are two fractional tracks, each
 ///   with a fraction of `{1fr}`, they will each take up half of the remaining
-///   space.
+///   space. Note that a grid nested in an `{auto}` track of another grid
+///   receives all space available to that track, so its fractional tracks
+///   make the outer track as wide as possible. Use `{auto}` tracks in the
+///   inner grid to have the outer track fit the inner grid's natural size.
 ///
 /// To specify a single track, the array can be omitted in favor of a single
 /// value. To specify multiple `{auto}` tracks, enter the number of tracks
@@ -110,6 +116,20 @@ use crate::visualize::{Paint, Stroke};
 /// )
 /// ```
 ///
+/// A child may also be a function. It is called with the column and row of
+/// the cell it ends up in and should return the cell's content. In combination
+/// with [`grid.cell`]($grid.cell), a function body receives the position of
+/// the cell's top-left corner, even if the cell spans multiple columns or rows.
+///
+/// ```example
+/// #grid(
+///   columns: 3,
+///   gutter: 5pt,
+///   grid.cell(colspan: 2, (x, y) => [(#x, #y)]),
+///   ..((x, y) => [(#x, #y)],) * 4
+/// )
+/// ```
+///
 /// # Styling the grid
 /// The grid's appearance can be customized through different parameters. These
 /// are the most important ones:
@@ -154,35 +174,315 @@ pub struct GridElem {
     /// with that many `{auto}`-sized columns. Note that opposed to rows and
     /// gutters, providing a single track size will only ever create a single
     /// column.
+    ///
+    /// To repeat a pattern of columns, multiply an array of track sizes. All
+    /// fractional columns share the space that is left after the other
+    /// columns were sized, across all repetitions of the pattern. In the
+    /// example below, both value columns thus get the same width.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (auto, 1fr) * 2,
+    ///   gutter: 4pt,
+    ///   [*Name:*], [Jane], [*Age:*], [32],
+    /// )
+    /// ```
+    ///
+    /// A track can also be `{"auto-fill"}` to take up whatever space is left
+    /// after the other tracks were sized. This is the same as `{1fr}`, so
+    /// multiple such tracks split the leftover space evenly.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (2cm, 1cm, "auto-fill"),
+    ///   fill: (x, y) => if x == 2 { aqua },
+    ///   [Fixed], [Fixed], [The rest],
+    /// )
+    /// ```
     #[borrowed]
     pub columns: TrackSizings,
 
     /// The row sizes.
     ///
-    /// If there are more cells than fit the defined rows, the last row is
-    /// repeated until there are no more cells.
+    /// Like for columns, an integer creates that many `{auto}`-sized rows.
+    /// Rows never limit the number of cells and never add columns: Cells are
+    /// always placed row by row into the columns. If there are more cells than
+    /// fit the defined rows, the last row is repeated until there are no more
+    /// cells. If there are fewer cells, the remaining rows stay empty, which
+    /// makes `{auto}`-sized rows collapse.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 2,
+    ///   rows: 2,
+    ///   stroke: 0.5pt,
+    ///   inset: 3pt,
+    ///   [A], [B], [C], [D], [E],
+    /// )
+    /// ```
     #[borrowed]
     pub rows: TrackSizings,
 
     /// The gaps between rows and columns.
     ///
+    /// A single size or an array of sizes applies to both axes. To configure
+    /// the two axes differently in one argument, pass a dictionary with the
+    /// keys `x` (for the column gutter) and `y` (for the row gutter). An
+    /// axis omitted from the dictionary has no gutter.
+    ///
     /// If there are more gutters than defined sizes, the last gutter is repeated.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 3,
+    ///   gutter: (x: 12pt, y: 4pt),
+    ///   ..range(6).map(i => rect[#i]),
+    /// )
+    /// ```
+    ///
+    /// Gutters can also be [fractional]($fraction). They then share the space
+    /// that remains after all other tracks were sized with the grid's
+    /// fractional tracks, in proportion to their fractions. This spreads
+    /// fixed-size columns evenly across the available width.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (2cm, 2cm, 2cm),
+    ///   column-gutter: 1fr,
+    ///   ..range(3).map(i => rect(width: 100%)[#i]),
+    /// )
+    /// ```
     #[external]
-    pub gutter: TrackSizings,
+    pub gutter: Gutter,
 
-    /// The gaps between columns. Takes precedence over `gutter`.
+    /// An alias for [`gutter`]($grid.gutter), matching the name of the
+    /// [stack's]($stack.gap) spacing argument. Like `gutter`, it sets both
+    /// the [`column-gutter`]($grid.column-gutter) and the
+    /// [`row-gutter`]($grid.row-gutter). If both `gap` and `gutter` are
+    /// given, `gutter` takes precedence.
+    ///
+    /// ```example
+    /// #grid(columns: 2, gap: 4pt, rect(), rect(), rect(), rect())
+    /// ```
+    #[external]
+    pub gap: Gutter,
+
+    /// The gaps between columns. Takes precedence over `gutter`, even when the
+    /// latter provides an `x` entry.
     #[parse(
-        let gutter = args.named("gutter")?;
-        args.named("column-gutter")?.or_else(|| gutter.clone())
+        let gap: Option<Gutter> = args.named("gap")?;
+        let gutter: Option<Gutter> = args.named("gutter")?.or(gap);
+        args.named("column-gutter")?.or_else(|| gutter.as_ref().map(|g| g.0.x.clone()))
     )]
     #[borrowed]
     pub column_gutter: TrackSizings,
 
-    /// The gaps between rows. Takes precedence over `gutter`.
-    #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
+    /// The gaps between rows. Takes precedence over `gutter`, even when the
+    /// latter provides a `y` entry.
+    ///
+    /// See [`collapse-empty-rows`]($grid.collapse-empty-rows) for how empty
+    /// `{auto}` rows affect these gaps.
+    #[parse(args.named("row-gutter")?.or_else(|| gutter.map(|g| g.0.y)))]
     #[borrowed]
     pub row_gutter: TrackSizings,
 
+    /// Blank gaps of a fixed height to leave after specific rows.
+    ///
+    /// Each entry is an array of a row's index, counting from zero, and the
+    /// height of the gap after that row. The gap replaces the
+    /// [`row-gutter`]($grid.row-gutter) at that position and leaves all other
+    /// gaps untouched, so groups of rows can be set apart without spacing out
+    /// every row. A gap after the last row has no effect.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 2,
+    ///   fill: luma(230),
+    ///   spacer-rows: ((1, 8pt),),
+    ///   [A], [1], [B], [2],
+    ///   [C], [3], [D], [4],
+    /// )
+    /// ```
+    #[borrowed]
+    pub spacer_rows: Vec<SpacerRow>,
+
+    /// The gap between the [header]($grid.header) and the first body row.
+    ///
+    /// When set, this replaces the [`row-gutter`]($grid.row-gutter) below the
+    /// header, including when the header is repeated on later pages. An
+    /// `{auto}` gap uses the regular row gutter. The gap is left in addition
+    /// to any stroke below the header.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 2,
+    ///   row-gutter: 2pt,
+    ///   header-gutter: 8pt,
+    ///   fill: luma(230),
+    ///   grid.header[*Fruit*][*Count*],
+    ///   [Apples], [2],
+    ///   [Pears], [3],
+    /// )
+    /// ```
+    pub header_gutter: Smart<Rel<Length>>,
+
+    /// How cells spanning multiple `{auto}` columns size those columns.
+    ///
+    /// By default, a [colspan]($grid.cell.colspan) only widens the last
+    /// `{auto}` column it spans. When enabled, auto columns are first sized
+    /// to fit the cells that span only them, like in CSS Grid. Afterwards,
+    /// cells spanning multiple columns are considered in the order of
+    /// increasing colspan and grow all of their spanned `{auto}` columns
+    /// proportionally to their widths if they don't fit yet.
+    ///
+    /// ```example
+    /// #set grid(stroke: 0.5pt)
+    /// #grid(
+    ///   columns: 3,
+    ///   [A], [Longer B], [C],
+    ///   grid.cell(colspan: 2)[A very wide cell],
+    /// )
+    /// #grid(
+    ///   columns: 3,
+    ///   distribute-spans: true,
+    ///   [A], [Longer B], [C],
+    ///   grid.cell(colspan: 2)[A very wide cell],
+    /// )
+    /// ```
+    #[default(false)]
+    pub distribute_spans: bool,
+
+    /// Whether `{auto}` rows without any height are separated from their
+    /// neighbours by a [row gutter]($grid.row-gutter).
+    ///
+    /// By default, each empty row gets its own gap, so an empty row between
+    /// two others doubles the gap between them. When enabled, an empty row,
+    /// e.g. one whose cells all have empty content, doesn't get a gap above
+    /// it, leaving a single gap between the rows around it. This doesn't
+    /// apply to the rows of a repeated header and to rows with cells
+    /// spanning other rows.
+    ///
+    /// ```example
+    /// #set grid(row-gutter: 6pt)
+    /// #grid(rect[A], [], rect[B])
+    /// #grid(collapse-empty-rows: true, rect[A], [], rect[B])
+    /// ```
+    #[default(false)]
+    pub collapse_empty_rows: bool,
+
+    /// The grid's total width.
+    ///
+    /// By default, the grid is as wide as its columns. With a fixed width,
+    /// the columns resolve within that width instead of the available space:
+    /// `{auto}` columns size to their contents and fractional columns split
+    /// the remaining width. Relative columns are relative to this width, too.
+    ///
+    /// ```example
+    /// #grid(
+    ///   width: 60%,
+    ///   columns: (auto, 1fr),
+    ///   fill: (x, _) => (aqua, silver).at(x),
+    ///   inset: 4pt,
+    ///   [Label], [Fills the rest],
+    /// )
+    /// ```
+    pub width: Smart<Rel<Length>>,
+
+    /// The grid's total height.
+    ///
+    /// With a fixed height, fractional rows split whatever space the other
+    /// rows leave within that height. A grid with a fixed height doesn't
+    /// break across pages.
+    pub height: Smart<Rel<Length>>,
+
+    /// The maximum height of an `{auto}` row. Relative lengths are resolved
+    /// relative to the height of the grid's container.
+    ///
+    /// A row whose cells need more space than this is shortened to the
+    /// maximum height, including when it would otherwise break across pages.
+    /// What happens to the contents that don't fit, including whether they
+    /// continue on the next page, is configured with
+    /// [`row-overflow`]($grid.row-overflow).
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 2,
+    ///   gutter: 4pt,
+    ///   max-row-height: 2em,
+    ///   rect[Short], rect(lorem(12)),
+    /// )
+    /// ```
+    pub max_row_height: Option<Rel<Length>>,
+
+    /// What to do with cell contents that exceed the
+    /// [`max-row-height`]($grid.max-row-height).
+    ///
+    /// With `{"break"}`, the row isn't shortened, but continues on the next
+    /// page or column instead, such that none of its parts is taller than
+    /// the maximum height.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #grid(
+    ///   max-row-height: 30pt,
+    ///   row-overflow: "break",
+    ///   stroke: 0.5pt,
+    ///   lorem(12),
+    /// )
+    /// ```
+    #[default(RowOverflow::Clip)]
+    pub row_overflow: RowOverflow,
+
+    /// What to do when the grid's tracks are wider than the available space.
+    ///
+    /// By default, the grid extends beyond its container and a warning is
+    /// emitted. With `{"shrink"}`, the whole grid is scaled down until it
+    /// fits, `{"clip"}` cuts it off at the edge of the available space, and
+    /// `{"error"}` fails the compilation instead.
+    ///
+    /// Previously, grids overflowed their container silently. Documents
+    /// that now receive the warning can set this to `{"shrink"}` or
+    /// `{"clip"}` to make such grids fit.
+    ///
+    /// ```example
+    /// #set page(width: 120pt)
+    /// #grid(
+    ///   columns: (60pt,) * 3,
+    ///   overflow: "shrink",
+    ///   fill: aqua,
+    ///   [A], [B], [C],
+    /// )
+    /// ```
+    #[default(GridOverflow::Warn)]
+    pub overflow: GridOverflow,
+
+    /// Whether grids and tables are only laid out to probe how narrow they
+    /// can get, in which case they don't deal with being wider than the
+    /// available space.
+    #[internal]
+    #[ghost]
+    pub probing: bool,
+
+    /// Where the grid continues when it doesn't fit into the current region.
+    ///
+    /// In a page with multiple [columns]($page.columns), a grid that breaks
+    /// continues in the next column by default. With `{"region"}`, it skips
+    /// the remaining columns and continues in the next region that holds
+    /// them, typically the next page. Outside of columns, both settings
+    /// behave the same.
+    ///
+    /// The default is `{"column"}` so that grids in existing multi-column
+    /// documents keep their layout.
+    ///
+    /// ```example
+    /// #set page(height: 80pt, columns: 2)
+    /// #grid(
+    ///   break-to: "region",
+    ///   ..range(6).map(str),
+    /// )
+    /// ```
+    pub break_to: GridBreak,
+
     /// How to fill the cells.
     ///
     /// This can be a color or a function that returns a color. The function
@@ -299,19 +599,113 @@ pub struct GridElem {
     #[fold]
     pub stroke: Celled<Sides<Option<Option<Arc<Stroke>>>>>,
 
+    /// How much to round the grid's outer corners.
+    ///
+    /// The fills of the cells at the corners are clipped to the rounded
+    /// corners and the outer border follows them, while lines between cells
+    /// stay square. The radius is clamped to half the grid's smaller
+    /// dimension. See the [rectangle's documentation]($rect.radius) for more
+    /// details on the possible values.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 3,
+    ///   inset: 6pt,
+    ///   radius: 6pt,
+    ///   stroke: 1pt,
+    ///   fill: (_, y) => if y == 0 { aqua.lighten(50%) },
+    ///   [Name], [Age], [Job],
+    ///   [Alex], [28], [Baker],
+    ///   [Sam], [34], [Pilot],
+    /// )
+    /// ```
+    #[resolve]
+    #[fold]
+    pub radius: Corners<Option<Rel<Length>>>,
+
     /// How much to pad the cells' content.
     ///
-    /// You can find an example for this argument at the
+    /// Like [`fill`]($grid.fill) and [`align`]($grid.align), this can be a
+    /// single value, an array with one value per column, or a function of a
+    /// cell's column and row. Each value can pad all sides equally or be a
+    /// dictionary with `left`, `top`, `right`, `bottom`, `x`, `y`, and `rest`
+    /// keys. You can find an example for this argument at the
     /// [`table.inset`]($table.inset) parameter.
     #[fold]
     pub inset: Celled<Sides<Option<Rel<Length>>>>,
 
+    /// Whether to align cells on their decimal separator.
+    ///
+    /// You can find an example for this argument at the
+    /// [`table.decimal`]($table.decimal) parameter.
+    pub decimal: Celled<bool>,
+
+    /// Whether to visualize the resolved track sizes.
+    ///
+    /// When enabled, the grid draws a faint outline around each column and
+    /// row after their final sizes were determined and shades its gutter
+    /// tracks. This helps with understanding how `{auto}` and fractional
+    /// tracks were distributed. The overlay is drawn on top of the cells'
+    /// contents and does not affect layout.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (auto, 1fr, 2fr),
+    ///   gutter: 4pt,
+    ///   debug: true,
+    ///   [Auto], [One], [Two],
+    /// )
+    /// ```
+    #[default(false)]
+    pub debug: bool,
+
+    /// Children given as an array. They are placed after the positional
+    /// children. Spreading an array with `..` into the positional children
+    /// works just as well.
+    ///
+    /// ```example
+    /// #let cells = range(6).map(i => [#i])
+    /// #grid(columns: 3, gutter: 4pt, items: cells)
+    /// #grid(columns: 3, gutter: 4pt, ..cells)
+    /// ```
+    #[external]
+    pub items: Vec<GridChild>,
+
+    /// Cells given as an array of rows, each of which is an array of cells.
+    ///
+    /// The rows are placed after the other children, each starting on a new
+    /// row of the grid. If the other children end in the middle of a row, that
+    /// row is filled up with empty cells first, so the data always starts on
+    /// a row of its own. Only cells without an explicit position count
+    /// towards this. Rows with fewer cells than the grid has columns are
+    /// padded with empty cells. A row with too many cells continues on the
+    /// next row of the grid, which produces a warning. If no columns are
+    /// specified, the grid gets one `{auto}` column per cell of its longest
+    /// row.
+    ///
+    /// ```example
+    /// #let data = (
+    ///   ([Name], [Age], [City]),
+    ///   ([Alice], [31]),
+    ///   ([Bob], [27], [Berlin]),
+    /// )
+    /// #grid(gutter: 6pt, rows-data: data)
+    /// ```
+    #[borrowed]
+    pub rows_data: Vec<Vec<Packed<GridCell>>>,
+
     /// The contents of the grid cells, plus any extra grid lines specified
     /// with the [`grid.hline`]($grid.hline) and [`grid.vline`]($grid.vline)
     /// elements.
     ///
     /// The cells are populated in row-major order.
     #[variadic]
+    #[parse(
+        let items: Option<Vec<GridChild>> = args.named("items")?;
+        let mut children: Vec<GridChild> = args.all()?;
+        children.extend(items.unwrap_or_default());
+        children
+    )]
     pub children: Vec<GridChild>,
 }
 
@@ -343,10 +737,24 @@ impl LayoutMultiple for Packed<GridElem> {
     ) -> SourceResult<Fragment> {
         let inset = self.inset(styles);
         let align = self.align(styles);
-        let columns = self.columns(styles);
+        let mut columns = self.columns(styles);
+        let data = self.rows_data(styles);
+        if columns.0.is_empty() {
+            let widest = data.iter().map(|row| row_width(row, styles)).max();
+            columns = TrackSizings(smallvec![Sizing::Auto; widest.unwrap_or(0)]);
+        }
         let rows = self.rows(styles);
         let column_gutter = self.column_gutter(styles);
-        let row_gutter = self.row_gutter(styles);
+        let mut row_gutter = Cow::Borrowed(self.row_gutter(styles));
+        let spacer_rows = self.spacer_rows(styles);
+        let header_gutter = self.header_gutter(styles);
+        if (!spacer_rows.is_empty() || header_gutter.is_custom())
+            && row_gutter.0.is_empty()
+        {
+            // Spacers and the header gutter replace gutter rows, so there must
+            // be some.
+            row_gutter = Cow::Owned(TrackSizings(smallvec![Sizing::Rel(Rel::zero())]));
+        }
         let fill = self.fill(styles);
         let stroke = self.stroke(styles);
 
@@ -370,7 +778,14 @@ impl LayoutMultiple for Packed<GridElem> {
                 ResolvableGridChild::Item(item.to_resolvable(styles))
             }
         });
-        let grid = CellGrid::resolve(
+        let c = columns.0.len().max(1);
+        let offset = auto_cells_width(children.clone(), c, styles);
+        let data = expand_rows_data(engine, data, c, offset, "grid", styles, self.span());
+        let children = children.chain(
+            data.into_iter()
+                .map(|cell| ResolvableGridChild::Item(ResolvableGridItem::Cell(cell))),
+        );
+        let mut grid = CellGrid::resolve(
             tracks,
             gutter,
             children,
@@ -384,11 +799,167 @@ impl LayoutMultiple for Packed<GridElem> {
         )
         .trace(engine.world, tracepoint, self.span())?;
 
-        let layouter = GridLayouter::new(&grid, regions, styles, self.span());
+        for spacer in spacer_rows {
+            grid.set_row_gutter(spacer.row, spacer.height);
+        }
+        if let Smart::Custom(gap) = header_gutter {
+            grid.set_header_gutter(gap);
+        }
+
+        let width = self
+            .width(styles)
+            .map(|width| width.resolve(styles).relative_to(regions.base().x));
+        let height = self
+            .height(styles)
+            .map(|height| height.resolve(styles).relative_to(regions.base().y));
+        let max_row_height = self
+            .max_row_height(styles)
+            .map(|height| height.resolve(styles).relative_to(regions.base().y));
+        let layouter = GridLayouter::new(&grid, regions, styles, self.span())
+            .with_size(width, height)
+            .with_debug(self.debug(styles))
+            .with_decimal(self.decimal(styles))
+            .with_max_row_height(max_row_height)
+            .with_row_overflow(self.row_overflow(styles))
+            .with_break_to(self.break_to(styles))
+            .with_distribute_spans(self.distribute_spans(styles))
+            .with_collapse_empty_rows(self.collapse_empty_rows(styles))
+            .with_radius(self.radius(styles).unwrap_or_default());
 
         // Measure the columns and layout the grid row-by-row.
-        layouter.layout(engine)
+        let mut fragment = layouter.layout(engine)?;
+
+        // Deal with a grid that is wider than its container.
+        let span = self.span();
+        let overflow = self.overflow(styles);
+        handle_overflow(engine, &mut fragment, overflow, regions, "grid", styles, span)?;
+
+        Ok(fragment)
+    }
+}
+
+/// Deals with a grid that is wider than the available space of its regions,
+/// as configured by its `overflow` property. The `name` of the element is used
+/// in diagnostics.
+pub fn handle_overflow(
+    engine: &mut Engine,
+    fragment: &mut Fragment,
+    overflow: GridOverflow,
+    regions: Regions,
+    name: &str,
+    styles: StyleChain,
+    span: Span,
+) -> SourceResult<()> {
+    let available = regions.size.x;
+    let widest = fragment.iter().map(Frame::width).fold(Abs::zero(), Abs::max);
+    if !available.is_finite() || available.fits(widest) || GridElem::probing_in(styles) {
+        return Ok(());
+    }
+
+    match overflow {
+        GridOverflow::Warn => engine.tracer.warn(warning!(
+            span,
+            "{name} is wider than the available space";
+            hint: "set `overflow` to \"shrink\" or \"clip\" to make it fit"
+        )),
+        GridOverflow::Shrink => {
+            let factor = available / widest;
+            let ratio = Ratio::new(factor);
+            for frame in fragment.iter_mut() {
+                frame.transform(Transform::scale(ratio, ratio));
+                frame.set_size(frame.size() * factor);
+            }
+        }
+        GridOverflow::Clip => {
+            for frame in fragment.iter_mut() {
+                let size = Size::new(available.min(frame.width()), frame.height());
+                frame.clip(Path::rect(size));
+                frame.set_size(size);
+            }
+        }
+        GridOverflow::Error => bail!(
+            span,
+            "{name} is wider than the available space";
+            hint: "try reducing the width of its columns"
+        ),
+    }
+
+    Ok(())
+}
+
+/// The number of columns a row of cells given through `rows-data` spans.
+/// Cells spanning to the end of their row count as spanning one column.
+pub fn row_width<T: ResolvableCell>(row: &[T], styles: StyleChain) -> usize {
+    row.iter()
+        .map(|cell| cell.colspan(styles).map_or(1, NonZeroUsize::get))
+        .sum()
+}
+
+/// The number of grid positions taken by the automatically positioned cells
+/// among a grid's children. Headers and footers always end on a full row.
+pub fn auto_cells_width<T: ResolvableCell, I>(
+    children: impl IntoIterator<Item = ResolvableGridChild<T, I>>,
+    columns: usize,
+    styles: StyleChain,
+) -> usize {
+    let mut width = 0;
+    for child in children {
+        match child {
+            ResolvableGridChild::Header { .. } | ResolvableGridChild::Footer { .. } => {
+                width = width.div_ceil(columns) * columns;
+            }
+            ResolvableGridChild::Item(ResolvableGridItem::Cell(cell))
+                if cell.x(styles).is_auto() && cell.y(styles).is_auto() =>
+            {
+                width += match cell.colspan(styles) {
+                    Smart::Auto => columns - width % columns,
+                    Smart::Custom(colspan) => colspan.get(),
+                };
+            }
+            ResolvableGridChild::Item(_) => {}
+        }
     }
+    width
+}
+
+/// Turns a grid's `rows-data` into a flat list of cells, padding each row
+/// with empty cells up to a multiple of the column count. The `offset` is
+/// the number of positions taken by the cells before the data, whose last
+/// row is padded as well. The `name` of the element is used in warnings.
+pub fn expand_rows_data<T: ResolvableCell + Clone + Default>(
+    engine: &mut Engine,
+    data: &[Vec<T>],
+    columns: usize,
+    offset: usize,
+    name: &str,
+    styles: StyleChain,
+    span: Span,
+) -> Vec<T> {
+    let mut cells = vec![];
+    if !data.is_empty() {
+        let padding = (columns - offset % columns) % columns;
+        cells.extend(std::iter::repeat_with(T::default).take(padding));
+    }
+
+    for (i, row) in data.iter().enumerate() {
+        let width = row_width(row, styles);
+        if width > columns {
+            engine.tracer.warn(warning!(
+                span,
+                "row {} of `rows-data` has {width} cells, but the {name} only has \
+                 {columns} columns",
+                i + 1;
+                hint: "the remaining cells continue on the next row"
+            ));
+        }
+
+        cells.extend(row.iter().cloned());
+        // A last cell that spans to the end of its row needs no padding.
+        let fills = row.last().is_some_and(|cell| cell.colspan(styles).is_auto());
+        let padding = if fills { 0 } else { (columns - width % columns) % columns };
+        cells.extend(std::iter::repeat_with(T::default).take(padding));
+    }
+    cells
 }
 
 /// Track sizing definitions.
@@ -398,9 +969,103 @@ pub struct TrackSizings(pub SmallVec<[Sizing; 4]>);
 cast! {
     TrackSizings,
     self => self.0.into_value(),
-    sizing: Sizing => Self(smallvec![sizing]),
+    sizing: TrackSizing => Self(smallvec![sizing.0]),
     count: NonZeroUsize => Self(smallvec![Sizing::Auto; count.get()]),
-    values: Array => Self(values.into_iter().map(Value::cast).collect::<StrResult<_>>()?),
+    values: Array => Self(
+        values
+            .into_iter()
+            .map(|value| value.cast::<TrackSizing>().map(|sizing| sizing.0))
+            .collect::<StrResult<_>>()?
+    ),
+}
+
+/// The size of a single grid track.
+struct TrackSizing(Sizing);
+
+cast! {
+    TrackSizing,
+    self => self.0.into_value(),
+    "auto-fill" => Self(Sizing::Fr(Fr::one())),
+    sizing: Sizing => Self(sizing),
+}
+
+/// A gap of a fixed height after a specific grid row.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SpacerRow {
+    /// The index of the row after which the gap is left.
+    pub row: usize,
+    /// The height of the gap.
+    pub height: Rel<Length>,
+}
+
+cast! {
+    SpacerRow,
+    self => array![self.row, self.height].into_value(),
+    array: Array => {
+        let mut iter = array.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), Some(b), None) => Self { row: a.cast()?, height: b.cast()? },
+            _ => bail!("spacer row array must contain exactly two entries"),
+        }
+    },
+}
+
+/// What to do with the contents of a grid row that exceed its maximum height.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RowOverflow {
+    /// Clip the contents to the row.
+    #[default]
+    Clip,
+    /// Let the contents extend beyond the row, over the following ones.
+    Visible,
+    /// Continue the row in the next region, so that each of its parts is at
+    /// most as tall as the maximum height. Rows that can't break, for
+    /// example in a header or an unbreakable block, are clipped instead.
+    Break,
+}
+
+/// What to do with a grid that is wider than the available space.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum GridOverflow {
+    /// Let the grid extend beyond its container and emit a warning.
+    #[default]
+    Warn,
+    /// Scale the grid down to fit the available width.
+    Shrink,
+    /// Clip the grid at the edge of the available space.
+    Clip,
+    /// Fail with an error.
+    Error,
+}
+
+/// Where a grid continues when it breaks.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum GridBreak {
+    /// Continue in the next column, or on the next page after the last
+    /// column.
+    #[default]
+    Column,
+    /// Continue in the next region that holds the columns, typically the
+    /// next page, leaving the remaining columns empty.
+    Region,
+}
+
+/// Gutter definitions for both axes of a grid.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Gutter(pub Axes<TrackSizings>);
+
+cast! {
+    Gutter,
+    self => dict! { "x" => self.0.x, "y" => self.0.y }.into_value(),
+    sizings: TrackSizings => Self(Axes::splat(sizings)),
+    mut dict: Dict => {
+        let mut take =
+            |key| dict.take(key).ok().map(TrackSizings::from_value).transpose();
+        let x = take("x")?.unwrap_or_default();
+        let y = take("y")?.unwrap_or_default();
+        dict.finish(&["x", "y"])?;
+        Self(Axes::new(x, y))
+    },
 }
 
 /// Any child of a grid element.
@@ -421,6 +1086,9 @@ cast! {
     v: Content => {
         v.try_into()?
     },
+    v: Func => Self::Item(GridItem::Cell(Packed::new(GridCell::new(
+        GridSlotElem::new(v).pack(),
+    )))),
 }
 
 impl TryFrom<Content> for GridChild {
@@ -541,6 +1209,19 @@ impl TryFrom<Content> for GridItem {
 /// If `repeat` is set to `true`, the header will be repeated across pages. For
 /// an example, refer to the [`table.header`]($table.header) element and the
 /// [`grid.stroke`]($grid.stroke) parameter.
+///
+/// # Headers and rowspans
+/// The header's rows are kept separate from the rows of the body, so a cell
+/// never spans across the boundary between them. A cell in the header that
+/// spans multiple rows makes the header grow to include all of those rows.
+/// Automatically positioned cells specified after the header are still placed
+/// below it, leaving any free slots in the header's rows empty. Only cells
+/// explicitly positioned into those rows with `y` become part of the header
+/// and repeat with it.
+///
+/// A cell in the body that spans multiple rows may break across pages like
+/// any other rows. On each page it covers the rows it spans there, and the
+/// repeated header is placed above the remainder.
 #[elem(name = "header", title = "Grid Header")]
 pub struct GridHeader {
     /// Whether this header should be repeated across pages.
@@ -728,7 +1409,11 @@ pub struct GridVLine {
 #[elem(name = "cell", title = "Grid Cell", Show)]
 pub struct GridCell {
     /// The cell's body.
+    ///
+    /// This may also be a function, which is called with the column and row
+    /// of the cell (as determined by layout) and returns its content.
     #[required]
+    #[parse(args.expect::<CellBody>("body")?.0)]
     pub body: Content,
 
     /// The cell's column (zero-indexed).
@@ -793,10 +1478,43 @@ pub struct GridCell {
     pub y: Smart<usize>,
 
     /// The amount of columns spanned by this cell.
-    #[default(NonZeroUsize::ONE)]
-    pub colspan: NonZeroUsize,
+    ///
+    /// If set to `{auto}`, the cell spans from its position to the end of its
+    /// row, however many columns remain there. This is handy for rows of
+    /// notes or totals that start in the middle of a row.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 4,
+    ///   gutter: 3pt,
+    ///   fill: luma(230),
+    ///   [A], [B], [C], [D],
+    ///   [E], grid.cell(colspan: auto)[Until the end],
+    /// )
+    /// ```
+    #[default(Smart::Custom(NonZeroUsize::ONE))]
+    pub colspan: Smart<NonZeroUsize>,
 
     /// The amount of rows spanned by this cell.
+    ///
+    /// A cell spanning multiple rows never makes those rows shorter than their
+    /// other cells require. If the spanned rows are together too short for
+    /// the cell, the last spanned `{auto}` row grows to fit it. Fixed-size
+    /// rows are never resized.
+    ///
+    /// A cell spanning past the last row adds empty rows to the grid, just
+    /// like cells placed past the last row do.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 2,
+    ///   stroke: 0.5pt,
+    ///   inset: 3pt,
+    ///   grid.cell(rowspan: 2)[Short],
+    ///   [A much \ taller cell],
+    ///   [B],
+    /// )
+    /// ```
     #[default(NonZeroUsize::ONE)]
     pub rowspan: NonZeroUsize,
 
@@ -818,7 +1536,82 @@ pub struct GridCell {
     /// When equal to `{auto}`, a cell spanning only fixed-size rows is
     /// unbreakable, while a cell spanning at least one `{auto}`-sized row is
     /// breakable.
+    ///
+    /// The rows spanned by an unbreakable cell are kept together: If they
+    /// don't fit into the remaining space, the whole rows, including all of
+    /// their other cells, move to the next page. Thus, marking a single cell
+    /// as unbreakable is enough to keep a row, like a row of totals, in one
+    /// piece.
+    ///
+    /// ```example
+    /// #set page(height: 5em)
+    /// #grid(
+    ///   columns: 2,
+    ///   [Apples], [2],
+    ///   [Pears], [3],
+    ///   grid.cell(breakable: false)[Total \ (all fruit)], [5],
+    /// )
+    /// ```
     pub breakable: Smart<bool>,
+
+    /// How to deal with content that is wider than the cell.
+    ///
+    /// By default, such content wraps within the cell. With `{"spill"}`,
+    /// the content may instead extend into the following cells of the same
+    /// row as long as they are empty, which is useful for sparse layouts
+    /// like timelines. It stops at the first cell that isn't empty and wraps
+    /// within the space gained up to there. Lines between the cells are
+    /// still drawn. Cells spanning multiple rows never spill.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (30pt,) * 4,
+    ///   grid.cell(overflow: "spill")[Kick-off meeting], [], [],
+    ///   [Launch],
+    /// )
+    /// ```
+    #[default(CellOverflow::Wrap)]
+    pub overflow: CellOverflow,
+
+    /// How much to rotate the cell's content.
+    ///
+    /// The content is rotated within the cell's inset. Whether the cell is
+    /// sized to fit the rotated content is determined by the
+    /// [`reflow`]($grid.cell.reflow) field.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 3,
+    ///   gutter: 4pt,
+    ///   align: bottom,
+    ///   ..([Name], [Quantity], [Price]).map(grid.cell.with(rotate: -90deg)),
+    ///   [Apple], [5], [\$2],
+    /// )
+    /// ```
+    pub rotate: Angle,
+
+    /// How much to scale the cell's content.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 2,
+    ///   gutter: 4pt,
+    ///   [Regular], grid.cell(scale: 50%)[Small print],
+    /// )
+    /// ```
+    #[default(Ratio::one())]
+    pub scale: Ratio,
+
+    /// Whether the cell is sized to fit its [rotated]($grid.cell.rotate) or
+    /// [scaled]($grid.cell.scale) content.
+    ///
+    /// If set to `{true}`, the cell's size takes the transformed bounding
+    /// box of its content into account, so rotated headers get the room they
+    /// need. If set to `{false}`, the cell is sized to the original content
+    /// and the transformed content may extend beyond it, like with
+    /// [`rotate`]($rotate.reflow) and [`scale`]($scale.reflow).
+    #[default(true)]
+    pub reflow: bool,
 }
 
 cast! {
@@ -837,6 +1630,7 @@ impl ResolvableCell for Packed<GridCell> {
         mut self,
         x: usize,
         y: usize,
+        colspan: NonZeroUsize,
         fill: &Option<Paint>,
         align: Smart<Alignment>,
         inset: Sides<Option<Rel<Length>>>,
@@ -845,7 +1639,6 @@ impl ResolvableCell for Packed<GridCell> {
         styles: StyleChain,
     ) -> Cell {
         let cell = &mut *self;
-        let colspan = cell.colspan(styles);
         let rowspan = cell.rowspan(styles);
         let breakable = cell.breakable(styles).unwrap_or(breakable);
         let fill = cell.fill(styles).unwrap_or_else(|| fill.clone());
@@ -866,6 +1659,7 @@ impl ResolvableCell for Packed<GridCell> {
         let stroke = cell_stroke.fold(stroke).map(Option::flatten);
         cell.push_x(Smart::Custom(x));
         cell.push_y(Smart::Custom(y));
+        cell.push_colspan(Smart::Custom(colspan));
         cell.push_fill(Smart::Custom(fill.clone()));
         cell.push_align(match align {
             Smart::Custom(align) => {
@@ -893,6 +1687,8 @@ impl ResolvableCell for Packed<GridCell> {
             }),
         );
         cell.push_breakable(Smart::Custom(breakable));
+        let spill = cell.overflow(styles) == CellOverflow::Spill;
+        let empty = cell.body().is_empty();
         Cell {
             body: self.pack(),
             fill,
@@ -901,6 +1697,8 @@ impl ResolvableCell for Packed<GridCell> {
             stroke,
             stroke_overridden,
             breakable,
+            spill,
+            empty,
         }
     }
 
@@ -912,7 +1710,7 @@ impl ResolvableCell for Packed<GridCell> {
         (**self).y(styles)
     }
 
-    fn colspan(&self, styles: StyleChain) -> NonZeroUsize {
+    fn colspan(&self, styles: StyleChain) -> Smart<NonZeroUsize> {
         (**self).colspan(styles)
     }
 
@@ -926,8 +1724,77 @@ impl ResolvableCell for Packed<GridCell> {
 }
 
 impl Show for Packed<GridCell> {
-    fn show(&self, _engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        show_grid_cell(self.body().clone(), self.inset(styles), self.align(styles))
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let body = match self.body().to_packed::<GridSlotElem>() {
+            Some(slot) => slot.call(engine, self.x(styles), self.y(styles), styles)?,
+            None => self.body().clone(),
+        };
+        show_grid_cell(
+            body,
+            self.inset(styles),
+            self.align(styles),
+            self.rotate(styles),
+            self.scale(styles),
+            self.reflow(styles),
+        )
+    }
+}
+
+/// How a grid cell deals with content that is wider than itself.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum CellOverflow {
+    /// The content wraps within the cell.
+    #[default]
+    Wrap,
+    /// The content extends into the following empty cells of its row.
+    Spill,
+}
+
+/// The body of a grid cell, which may be given as a function of the cell's
+/// position.
+struct CellBody(Content);
+
+cast! {
+    CellBody,
+    v: Content => Self(v),
+    v: Func => Self(GridSlotElem::new(v).pack()),
+}
+
+/// A placeholder for a cell body that is produced by a function once the
+/// cell's position is known.
+#[elem(Construct, Show)]
+struct GridSlotElem {
+    /// The function to call with the cell's column and row.
+    #[required]
+    func: Func,
+}
+
+impl Packed<GridSlotElem> {
+    /// Produce the body for the cell at the given position.
+    fn call(
+        &self,
+        engine: &mut Engine,
+        x: Smart<usize>,
+        y: Smart<usize>,
+        styles: StyleChain,
+    ) -> SourceResult<Content> {
+        Ok(self
+            .func()
+            .call(engine, Context::new(None, Some(styles)).track(), [x, y])?
+            .display())
+    }
+}
+
+impl Construct for GridSlotElem {
+    fn construct(_: &mut Engine, args: &mut Args) -> SourceResult<Content> {
+        bail!(args.span, "cannot be constructed manually");
+    }
+}
+
+impl Show for Packed<GridSlotElem> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        // The slot is only filled in when shown as part of a grid cell.
+        Ok(Content::empty())
     }
 }
 
@@ -943,9 +1810,26 @@ pub fn show_grid_cell(
     mut body: Content,
     inset: Smart<Sides<Option<Rel<Length>>>>,
     align: Smart<Alignment>,
+    rotate: Angle,
+    scale: Ratio,
+    reflow: bool,
 ) -> SourceResult<Content> {
     let inset = inset.unwrap_or_default().map(Option::unwrap_or_default);
 
+    // Transform the content inside of the inset, so that the inset keeps
+    // surrounding the transformed content.
+    if scale != Ratio::one() {
+        body = ScaleElem::new(body)
+            .with_x(scale)
+            .with_y(scale)
+            .with_reflow(reflow)
+            .pack();
+    }
+
+    if !rotate.is_zero() {
+        body = RotateElem::new(body).with_angle(rotate).with_reflow(reflow).pack();
+    }
+
     if inset != Sides::default() {
         // Only pad if some inset is not 0pt.
         // Avoids a bug where using .padded() in any way inside Show causes
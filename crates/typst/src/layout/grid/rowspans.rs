@@ -552,7 +552,7 @@ impl<'a> GridLayouter<'a> {
             }
         }
 
-        let width = self.cell_spanned_width(cell, parent.x);
+        let width = self.cell_content_width(cell, parent.x, parent.y);
         CellMeasurementData {
             width,
             height,
@@ -20,10 +20,11 @@ use crate::layout::{
     Point, Regions, Size, Sizing, Spacing,
 };
 use crate::math::{EquationElem, MathParItem};
-use crate::model::{Linebreaks, ParElem};
+use crate::model::{LastLine, Linebreaks, ParElem, TabAlignment};
 use crate::syntax::Span;
 use crate::text::{
-    Lang, LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TextElem,
+    Lang, LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TabElem,
+    TextElem,
 };
 use crate::util::Numeric;
 use crate::World;
@@ -36,6 +37,7 @@ pub(crate) fn layout_inline(
     consecutive: bool,
     region: Size,
     expand: bool,
+    exclusion: Option<Exclusion>,
 ) -> SourceResult<Fragment> {
     #[comemo::memoize]
     #[allow(clippy::too_many_arguments)]
@@ -50,6 +52,7 @@ pub(crate) fn layout_inline(
         consecutive: bool,
         region: Size,
         expand: bool,
+        exclusion: Option<Exclusion>,
     ) -> SourceResult<Fragment> {
         let mut locator = Locator::chained(locator);
         let mut engine = Engine {
@@ -69,11 +72,29 @@ pub(crate) fn layout_inline(
         // each and every line from scratch.
         let p = prepare(&mut engine, children, &text, segments, spans, styles, region)?;
 
-        // Break the paragraph into lines.
-        let lines = linebreak(&engine, &p, region.x - p.hang);
+        // Break the paragraph into lines and stack them into one frame per
+        // line. If a float excludes space beside the first lines, we don't
+        // know in advance how many lines are affected, so we narrow more and
+        // more lines until all lines beside the float are narrowed.
+        let mut narrowing = Narrowing::default();
+        loop {
+            let lines = linebreak(&engine, &p, region.x - p.hang, narrowing);
+            let frames = finalize(&mut engine, &p, &lines, region, expand, narrowing)?;
+            let Some(exclusion) = exclusion else {
+                return Ok(prevent_orphans(&p, frames));
+            };
+
+            let beside = lines_beside(&frames, p.leading, exclusion.height);
+            if beside <= narrowing.lines {
+                return Ok(prevent_orphans(&p, frames));
+            }
 
-        // Stack the lines into one frame per region.
-        finalize(&mut engine, &p, &lines, region, expand)
+            narrowing = Narrowing {
+                side: exclusion.side,
+                amount: exclusion.width,
+                lines: beside,
+            };
+        }
     }
 
     let fragment = cached(
@@ -87,12 +108,57 @@ pub(crate) fn layout_inline(
         consecutive,
         region,
         expand,
+        exclusion,
     )?;
 
     engine.locator.visit_frames(&fragment);
     Ok(fragment)
 }
 
+/// Space beside the first lines of a paragraph that is occupied by a float
+/// and must be kept free of text.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct Exclusion {
+    /// The side of the lines at which the space is excluded.
+    pub side: FixedAlignment,
+    /// The excluded width, including clearance.
+    pub width: Abs,
+    /// How far the excluded space extends down from the top of the paragraph.
+    pub height: Abs,
+}
+
+/// How much the first lines of a paragraph are narrowed.
+#[derive(Debug, Copy, Clone)]
+struct Narrowing {
+    /// The side at which the lines are narrowed.
+    side: FixedAlignment,
+    /// By how much the lines are narrowed.
+    amount: Abs,
+    /// How many lines are narrowed.
+    lines: usize,
+}
+
+impl Narrowing {
+    /// By how much the line with the given index is narrowed.
+    fn at(&self, index: usize) -> Abs {
+        if index < self.lines {
+            self.amount
+        } else {
+            Abs::zero()
+        }
+    }
+}
+
+impl Default for Narrowing {
+    fn default() -> Self {
+        Self {
+            side: FixedAlignment::Start,
+            amount: Abs::zero(),
+            lines: 0,
+        }
+    }
+}
+
 /// Range of a substring of text.
 type Range = std::ops::Range<usize>;
 
@@ -122,8 +188,14 @@ struct Preparation<'a> {
     align: FixedAlignment,
     /// Whether to justify the paragraph.
     justify: bool,
+    /// The alignment of the paragraph's last line if it differs from `align`.
+    last_align: Option<FixedAlignment>,
+    /// Whether to justify the paragraph's last line, too.
+    justify_last: bool,
     /// The paragraph's hanging indent.
     hang: Abs,
+    /// The paragraph's tab stops, sorted by position.
+    tabs: Vec<(Abs, TabAlignment)>,
     /// Whether to add spacing between CJK and Latin characters.
     cjk_latin_spacing: bool,
     /// Whether font fallback is enabled for this paragraph.
@@ -188,6 +260,8 @@ enum Segment<'a> {
     Text(usize),
     /// Horizontal spacing between other segments.
     Spacing(Spacing),
+    /// A tab marker.
+    Tab,
     /// A mathematical equation.
     Equation(&'a Packed<EquationElem>, Vec<MathParItem>),
     /// A box with arbitrary content.
@@ -201,7 +275,7 @@ impl Segment<'_> {
     fn len(&self) -> usize {
         match *self {
             Self::Text(len) => len,
-            Self::Spacing(_) => SPACING_REPLACE.len_utf8(),
+            Self::Spacing(_) | Self::Tab => SPACING_REPLACE.len_utf8(),
             Self::Box(_, frac) => {
                 (if frac { SPACING_REPLACE } else { OBJ_REPLACE }).len_utf8()
             }
@@ -222,6 +296,8 @@ enum Item<'a> {
     Absolute(Abs),
     /// Fractional spacing between other items.
     Fractional(Fr, Option<(&'a Packed<BoxElem>, StyleChain<'a>)>),
+    /// A tab marker, whose advance is only known once the line is committed.
+    Tab,
     /// Layouted inline-level content.
     Frame(Frame),
     /// Metadata.
@@ -249,7 +325,9 @@ impl<'a> Item<'a> {
     fn len(&self) -> usize {
         match self {
             Self::Text(shaped) => shaped.text.len(),
-            Self::Absolute(_) | Self::Fractional(_, _) => SPACING_REPLACE.len_utf8(),
+            Self::Absolute(_) | Self::Fractional(_, _) | Self::Tab => {
+                SPACING_REPLACE.len_utf8()
+            }
             Self::Frame(_) => OBJ_REPLACE.len_utf8(),
             Self::Meta(_) => 0,
         }
@@ -261,7 +339,7 @@ impl<'a> Item<'a> {
             Self::Text(shaped) => shaped.width,
             Self::Absolute(v) => *v,
             Self::Frame(frame) => frame.width(),
-            Self::Fractional(_, _) | Self::Meta(_) => Abs::zero(),
+            Self::Fractional(_, _) | Self::Tab | Self::Meta(_) => Abs::zero(),
         }
     }
 }
@@ -417,13 +495,13 @@ fn collect<'a>(
     let mut iter = children.iter().peekable();
 
     let first_line_indent = ParElem::first_line_indent_in(*styles);
-    if !first_line_indent.is_zero()
-        && consecutive
+    if !first_line_indent.amount.is_zero()
+        && (consecutive || first_line_indent.all)
         && AlignElem::alignment_in(*styles).resolve(*styles).x
             == TextElem::dir_in(*styles).start().into()
     {
         full.push(SPACING_REPLACE);
-        segments.push((Segment::Spacing(first_line_indent.into()), *styles));
+        segments.push((Segment::Spacing(first_line_indent.amount.into()), *styles));
     }
 
     let hang = ParElem::hanging_indent_in(*styles);
@@ -475,6 +553,9 @@ fn collect<'a>(
 
             full.push(SPACING_REPLACE);
             Segment::Spacing(*elem.amount())
+        } else if child.is::<TabElem>() {
+            full.push(SPACING_REPLACE);
+            Segment::Tab
         } else if let Some(elem) = child.to_packed::<LinebreakElem>() {
             let c = if elem.justify(styles) { '\u{2028}' } else { '\n' };
             full.push(c);
@@ -500,6 +581,7 @@ fn collect<'a>(
                         Some('"')
                     } else if child.is::<SpaceElem>()
                         || child.is::<HElem>()
+                        || child.is::<TabElem>()
                         || child.is::<LinebreakElem>()
                     {
                         Some(SPACING_REPLACE)
@@ -592,6 +674,7 @@ fn prepare<'a>(
                     items.push(Item::Fractional(v, None));
                 }
             },
+            Segment::Tab => items.push(Item::Tab),
             Segment::Equation(_, par_items) => {
                 for item in par_items {
                     match item {
@@ -629,6 +712,16 @@ fn prepare<'a>(
         add_cjk_latin_spacing(&mut items);
     }
 
+    let mut tabs: Vec<_> = ParElem::tabs_in(styles)
+        .into_iter()
+        .map(|stop| (stop.pos.resolve(styles), stop.align))
+        .collect();
+    tabs.sort_by(|a, b| a.0.cmp(&b.0));
+
+    // The last line's alignment only matters for justified paragraphs.
+    let justify = ParElem::justify_in(styles);
+    let last_line = if justify { ParElem::last_line_in(styles) } else { Smart::Auto };
+
     Ok(Preparation {
         bidi,
         items,
@@ -636,8 +729,16 @@ fn prepare<'a>(
         hyphenate: shared_get(styles, children, TextElem::hyphenate_in),
         lang: shared_get(styles, children, TextElem::lang_in),
         align: AlignElem::alignment_in(styles).resolve(styles).x,
-        justify: ParElem::justify_in(styles),
-        hang: ParElem::hanging_indent_in(styles),
+        justify,
+        last_align: match last_line {
+            Smart::Custom(LastLine::Align(align)) => Some(align.resolve(styles)),
+            _ => None,
+        },
+        justify_last: last_line == Smart::Custom(LastLine::Justify),
+        // A negative hanging indent only indents the first line, which is
+        // done by the spacing inserted during collection.
+        hang: ParElem::hanging_indent_in(styles).max(Abs::zero()),
+        tabs,
         cjk_latin_spacing,
         fallback: TextElem::fallback_in(styles),
         leading: ParElem::leading_in(styles),
@@ -781,7 +882,12 @@ fn shared_get<T: PartialEq>(
 }
 
 /// Find suitable linebreaks.
-fn linebreak<'a>(engine: &Engine, p: &'a Preparation<'a>, width: Abs) -> Vec<Line<'a>> {
+fn linebreak<'a>(
+    engine: &Engine,
+    p: &'a Preparation<'a>,
+    width: Abs,
+    narrowing: Narrowing,
+) -> Vec<Line<'a>> {
     let linebreaks = p.linebreaks.unwrap_or_else(|| {
         if p.justify {
             Linebreaks::Optimized
@@ -791,8 +897,8 @@ fn linebreak<'a>(engine: &Engine, p: &'a Preparation<'a>, width: Abs) -> Vec<Lin
     });
 
     match linebreaks {
-        Linebreaks::Simple => linebreak_simple(engine, p, width),
-        Linebreaks::Optimized => linebreak_optimized(engine, p, width),
+        Linebreaks::Simple => linebreak_simple(engine, p, width, narrowing),
+        Linebreaks::Optimized => linebreak_optimized(engine, p, width, narrowing),
     }
 }
 
@@ -803,6 +909,7 @@ fn linebreak_simple<'a>(
     engine: &Engine,
     p: &'a Preparation<'a>,
     width: Abs,
+    narrowing: Narrowing,
 ) -> Vec<Line<'a>> {
     let mut lines = Vec::with_capacity(16);
     let mut start = 0;
@@ -815,7 +922,7 @@ fn linebreak_simple<'a>(
         // If the line doesn't fit anymore, we push the last fitting attempt
         // into the stack and rebuild the line from the attempt's end. The
         // resulting line cannot be broken up further.
-        if !width.fits(attempt.width) {
+        if !(width - narrowing.at(lines.len())).fits(attempt.width) {
             if let Some((last_attempt, last_end)) = last.take() {
                 lines.push(last_attempt);
                 start = last_end;
@@ -826,7 +933,9 @@ fn linebreak_simple<'a>(
         // Finish the current line if there is a mandatory line break (i.e.
         // due to "\n") or if the line doesn't fit horizontally already
         // since then no shorter line will be possible.
-        if breakpoint == Breakpoint::Mandatory || !width.fits(attempt.width) {
+        if breakpoint == Breakpoint::Mandatory
+            || !(width - narrowing.at(lines.len())).fits(attempt.width)
+        {
             lines.push(attempt);
             start = end;
             last = None;
@@ -863,6 +972,7 @@ fn linebreak_optimized<'a>(
     engine: &Engine,
     p: &'a Preparation<'a>,
     width: Abs,
+    narrowing: Narrowing,
 ) -> Vec<Line<'a>> {
     /// The cost of a line or paragraph layout.
     type Cost = f64;
@@ -872,6 +982,7 @@ fn linebreak_optimized<'a>(
         pred: usize,
         total: Cost,
         line: Line<'a>,
+        count: usize,
     }
 
     // Cost parameters.
@@ -887,6 +998,7 @@ fn linebreak_optimized<'a>(
         pred: 0,
         total: 0.0,
         line: line(engine, p, 0..0, Breakpoint::Mandatory),
+        count: 0,
     }];
 
     let em = p.size;
@@ -904,8 +1016,10 @@ fn linebreak_optimized<'a>(
             let attempt = line(engine, p, start..end, breakpoint);
 
             // Determine how much the line's spaces would need to be stretched
-            // to make it the desired width.
-            let delta = width - attempt.width;
+            // to make it the desired width. The width of narrowed lines
+            // depends on the number of lines before, which we approximate by
+            // the line count along the best path to the predecessor.
+            let delta = width - narrowing.at(pred.count) - attempt.width;
             // Determine how much stretch are permitted.
             let adjust = if delta >= Abs::zero() {
                 attempt.stretchability()
@@ -988,7 +1102,12 @@ fn linebreak_optimized<'a>(
 
             // If this attempt is better than what we had before, take it!
             if best.as_ref().map_or(true, |best| best.total >= total) {
-                best = Some(Entry { pred: i, total, line: attempt });
+                best = Some(Entry {
+                    pred: i,
+                    total,
+                    line: attempt,
+                    count: pred.count + 1,
+                });
             }
         }
 
@@ -1016,8 +1135,12 @@ fn line<'a>(
     breakpoint: Breakpoint,
 ) -> Line<'a> {
     let end = range.end;
-    let mut justify =
-        p.justify && end < p.bidi.text.len() && breakpoint != Breakpoint::Mandatory;
+    let mut justify = p.justify
+        && if end < p.bidi.text.len() {
+            breakpoint != Breakpoint::Mandatory
+        } else {
+            p.justify_last
+        };
 
     if range.is_empty() {
         return Line {
@@ -1184,32 +1307,67 @@ fn line<'a>(
     }
 }
 
-/// Combine layouted lines into one frame per region.
+/// Build one frame per layouted line.
 fn finalize(
     engine: &mut Engine,
     p: &Preparation,
     lines: &[Line],
     region: Size,
     expand: bool,
-) -> SourceResult<Fragment> {
+    narrowing: Narrowing,
+) -> SourceResult<Vec<Frame>> {
     // Determine the paragraph's width: Full width of the region if we
     // should expand or there's fractional spacing, fit-to-width otherwise.
     let width = if !region.x.is_finite()
         || (!expand && lines.iter().all(|line| line.fr().is_zero()))
     {
-        region
-            .x
-            .min(p.hang + lines.iter().map(|line| line.width).max().unwrap_or_default())
+        let natural = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| line.width + narrowing.at(i))
+            .max()
+            .unwrap_or_default();
+        region.x.min(p.hang + natural)
     } else {
         region.x
     };
 
-    // Stack the lines into one frame per region.
-    let mut frames: Vec<Frame> = lines
+    lines
         .iter()
-        .map(|line| commit(engine, p, line, width, region.y))
-        .collect::<SourceResult<_>>()?;
+        .enumerate()
+        .map(|(i, line)| {
+            let amount = narrowing.at(i);
+            let mut frame = commit(engine, p, line, width - amount, region.y)?;
+            if !amount.is_zero() {
+                // Make space at the narrowed side of the line.
+                if narrowing.side == FixedAlignment::Start {
+                    frame.translate(Point::with_x(amount));
+                }
+                frame.size_mut().x += amount;
+            }
+            Ok(frame)
+        })
+        .collect()
+}
+
+/// Count how many lines start beside a float that extends the given height
+/// down from the top of the paragraph.
+fn lines_beside(frames: &[Frame], leading: Abs, height: Abs) -> usize {
+    let mut y = Abs::zero();
+    let mut count = 0;
+    for frame in frames {
+        if y >= height {
+            break;
+        }
+        y += frame.height() + leading;
+        count += 1;
+    }
+    count
+}
 
+/// Combine the line frames into one frame per region, merging lines to
+/// prevent orphans and widows.
+fn prevent_orphans(p: &Preparation, mut frames: Vec<Frame>) -> Fragment {
     // Prevent orphans.
     if frames.len() >= 2 && !frames[1].is_empty() {
         let second = frames.remove(1);
@@ -1225,7 +1383,7 @@ fn finalize(
         merge(first, second, p.leading);
     }
 
-    Ok(Fragment::frames(frames))
+    Fragment::frames(frames)
 }
 
 /// Merge two line frames
@@ -1280,6 +1438,19 @@ fn commit(
         }
     }
 
+    // Tab markers take up some of the remaining space. Lines with tabs are
+    // neither justified nor shifted by the paragraph's alignment, as that
+    // would move the content away from the stops.
+    let has_tabs = reordered.iter().any(|item| matches!(item, Item::Tab));
+    let tab_advances = if has_tabs && !starts_rtl {
+        let advances = tab_advances(p, &reordered, offset);
+        remaining -= advances.iter().copied().sum::<Abs>();
+        advances
+    } else {
+        vec![]
+    };
+    let mut tab_advances = tab_advances.into_iter();
+
     // Determine how much additional space is needed.
     // The justification_ratio is for the first step justification,
     // extra_justification is for the last step.
@@ -1295,7 +1466,7 @@ fn commit(
         // Attempt to reduce the length of the line, using shrinkability.
         justification_ratio = (remaining / shrink).max(-1.0);
         remaining = (remaining + shrink).min(Abs::zero());
-    } else if line.justify && fr.is_zero() {
+    } else if line.justify && fr.is_zero() && !has_tabs {
         // Attempt to increase the length of the line, using stretchability.
         if stretch > Abs::zero() {
             justification_ratio = (remaining / stretch).min(1.0);
@@ -1328,6 +1499,9 @@ fn commit(
             Item::Absolute(v) => {
                 offset += *v;
             }
+            Item::Tab => {
+                offset += tab_advances.next().unwrap_or_default();
+            }
             Item::Fractional(v, elem) => {
                 let amount = v.share(fr, remaining);
                 if let Some((elem, styles)) = elem {
@@ -1354,7 +1528,7 @@ fn commit(
     }
 
     // Remaining space is distributed now.
-    if !fr.is_zero() {
+    if !fr.is_zero() || has_tabs {
         remaining = Abs::zero();
     }
 
@@ -1362,9 +1536,15 @@ fn commit(
     let mut output = Frame::soft(size);
     output.set_baseline(top);
 
+    // The paragraph's last line may be aligned differently.
+    let align = match p.last_align {
+        Some(align) if line.end == p.bidi.text.len() => align,
+        _ => p.align,
+    };
+
     // Construct the line's frame.
     for (offset, frame) in frames {
-        let x = offset + p.align.position(remaining);
+        let x = offset + align.position(remaining);
         let y = top - frame.baseline();
         output.push_frame(Point::new(x, y), frame);
     }
@@ -1372,6 +1552,64 @@ fn commit(
     Ok(output)
 }
 
+/// Determine how far each tab marker in a line advances, given the line's
+/// items in visual order and the offset at which the first item starts.
+fn tab_advances(p: &Preparation, items: &[&Item], mut offset: Abs) -> Vec<Abs> {
+    let mut advances = vec![];
+    for (i, item) in items.iter().enumerate() {
+        let Item::Tab = item else {
+            offset += item.width();
+            continue;
+        };
+
+        // The content aligned at the stop reaches up to the next tab marker
+        // or the end of the line.
+        let rest = &items[i + 1..];
+        let end = rest.iter().position(|item| matches!(item, Item::Tab));
+        let following = &rest[..end.unwrap_or(rest.len())];
+
+        let advance = p
+            .tabs
+            .iter()
+            .find(|&&(pos, _)| pos > offset)
+            .map(|&(pos, align)| {
+                let width: Abs = following.iter().map(|item| item.width()).sum();
+                let anchor = match align {
+                    TabAlignment::Start => Abs::zero(),
+                    TabAlignment::Center => width / 2.0,
+                    TabAlignment::End => width,
+                    TabAlignment::Decimal => decimal_offset(following).unwrap_or(width),
+                };
+                (pos - anchor - offset).max(Abs::zero())
+            })
+            .unwrap_or_default();
+
+        offset += advance;
+        advances.push(advance);
+    }
+    advances
+}
+
+/// The distance from the start of the items to the first decimal point in
+/// them, if any.
+fn decimal_offset(items: &[&Item]) -> Option<Abs> {
+    let mut offset = Abs::zero();
+    for item in items {
+        let Item::Text(shaped) = item else {
+            offset += item.width();
+            continue;
+        };
+
+        for glyph in shaped.glyphs.iter() {
+            if glyph.c == '.' {
+                return Some(offset);
+            }
+            offset += glyph.x_advance.at(shaped.size);
+        }
+    }
+    None
+}
+
 /// Return a line's items in visual order.
 fn reorder<'a>(line: &'a Line<'a>) -> (Vec<&Item<'a>>, bool) {
     let mut reordered = vec![];
@@ -22,6 +22,10 @@ use crate::util::Numeric;
 /// - Relative to font size: `{2.5em}`
 ///
 /// You can multiply lengths with and divide them by integers and floats.
+/// Lengths in different units can also be added and subtracted freely, like
+/// in `{1cm + 12pt - 0.5em}`. The absolute units are combined right away,
+/// while the `em` part is kept as is and only resolved against the font size
+/// where the length is used.
 ///
 /// # Example
 /// ```example
@@ -3,7 +3,7 @@ use comemo::Tracked;
 use crate::diag::{At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{dict, func, Content, Context, Dict, StyleChain, Styles};
-use crate::layout::{Abs, Axes, LayoutMultiple, Regions, Size};
+use crate::layout::{Abs, Axes, GridElem, LayoutMultiple, Regions, Size};
 use crate::syntax::Span;
 
 /// Measures the layouted size of content.
@@ -40,6 +40,23 @@ use crate::syntax::Span;
 ///
 /// The measure function returns a dictionary with the entries `width` and
 /// `height`, both of type [`length`].
+///
+/// # Sizing to the widest of several contents
+/// To give a box the width of the widest of several contents, even if only
+/// one of them is displayed, measure each of them and pick the largest width
+/// with [`calc.max`]($calc.max). This keeps the width stable when the
+/// displayed content changes, for example across the buttons of a menu.
+///
+/// ```example
+/// #let labels = ([Open], [Save as], [Quit])
+/// #let button(body) = context {
+///   let width = calc.max(..labels.map(it => measure(it).width))
+///   box(width: width, fill: aqua, inset: 4pt, align(center, body))
+/// }
+///
+/// #button[Open] \
+/// #button[Quit]
+/// ```
 #[func(contextual)]
 pub fn measure(
     /// The engine.
@@ -65,3 +82,46 @@ pub fn measure(
     let Size { x, y } = frame.size();
     Ok(dict! { "width" => x, "height" => y })
 }
+
+/// Measures the intrinsic widths of content.
+///
+/// Returns a dictionary with the entries `min` and `max`, both of type
+/// [`length`]. The `min` width is the smallest width the content can be laid
+/// out in without overflowing, for text typically its longest word. The `max`
+/// width is the width the content takes when nothing is wrapped at all. These
+/// are the widths that `{auto}` columns of a [grid]($grid) are sized between,
+/// and they correspond to the `min-content` and `max-content` sizes of CSS.
+///
+/// Like [`measure`], this function requires context.
+///
+/// ```example
+/// #context {
+///   let (min, max) = intrinsic[A few words]
+///   [Between #min and #max wide.]
+/// }
+/// ```
+#[func(contextual)]
+pub fn intrinsic(
+    /// The engine.
+    engine: &mut Engine,
+    /// The callsite context.
+    context: Tracked<Context>,
+    /// The callsite span.
+    span: Span,
+    /// The content whose intrinsic widths to measure.
+    content: Content,
+) -> SourceResult<Dict> {
+    let styles = context.styles().at(span)?;
+
+    // Grids would otherwise react to overflowing the zero width of the
+    // minimum pass, e.g. by shrinking or warning.
+    let content = content.styled(GridElem::set_probing(true));
+    let width = |engine: &mut Engine, width| -> SourceResult<Abs> {
+        let pod = Regions::one(Size::new(width, Abs::inf()), Axes::splat(false));
+        Ok(content.measure(engine, styles, pod)?.into_frame().width())
+    };
+
+    let min = width(engine, Abs::zero())?;
+    let max = width(engine, Abs::inf())?;
+    Ok(dict! { "min" => min, "max" => max })
+}
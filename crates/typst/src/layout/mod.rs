@@ -4,6 +4,7 @@ mod abs;
 mod align;
 mod angle;
 mod axes;
+mod badge;
 mod columns;
 mod container;
 mod corners;
@@ -25,6 +26,8 @@ mod pad;
 mod page;
 mod place;
 mod point;
+#[path = "prerender.rs"]
+mod prerender_;
 mod ratio;
 mod regions;
 mod rel;
@@ -39,6 +42,7 @@ pub use self::abs::*;
 pub use self::align::*;
 pub use self::angle::*;
 pub use self::axes::*;
+pub use self::badge::*;
 pub use self::columns::*;
 pub use self::container::*;
 pub use self::corners::*;
@@ -57,6 +61,7 @@ pub use self::pad::*;
 pub use self::page::*;
 pub use self::place::*;
 pub use self::point::*;
+pub use self::prerender_::*;
 pub use self::ratio::*;
 pub use self::regions::Regions;
 pub use self::rel::*;
@@ -101,6 +106,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<VElem>();
     global.define_elem::<HElem>();
     global.define_elem::<BoxElem>();
+    global.define_elem::<BadgeElem>();
     global.define_elem::<BlockElem>();
     global.define_elem::<StackElem>();
     global.define_elem::<GridElem>();
@@ -115,7 +121,9 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<RotateElem>();
     global.define_elem::<HideElem>();
     global.define_func::<measure>();
+    global.define_func::<intrinsic>();
     global.define_func::<layout>();
+    global.define_func::<prerender>();
 }
 
 /// Root-level layout.
@@ -129,6 +137,11 @@ pub trait LayoutRoot {
 }
 
 /// Layout into multiple regions.
+///
+/// Layout of [`Content`] through this trait is memoized. Laying out the same
+/// content with the same styles into the same regions again, for instance a
+/// stack child when the stack is relayouted, reuses the previous fragment as
+/// long as all counters and states it observed are unchanged.
 pub trait LayoutMultiple {
     /// Layout into one frame per region.
     fn layout(
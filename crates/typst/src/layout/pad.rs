@@ -2,14 +2,30 @@ use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{elem, Content, Packed, Resolve, StyleChain};
 use crate::layout::{
-    Abs, Fragment, LayoutMultiple, Length, Point, Regions, Rel, Sides, Size,
+    Abs, Fr, Fragment, LayoutMultiple, Length, Point, Ratio, Regions, Rel, Sides, Size,
+    Spacing,
 };
+use crate::util::Numeric;
 
 /// Adds spacing around content.
 ///
 /// The spacing can be specified for each side individually, or for all sides at
 /// once by specifying a positional argument.
 ///
+/// Relative padding is resolved against the size of the padded result, i.e.
+/// the content plus its padding. Relative padding that adds up to `{100%}` or
+/// more along an axis can't be satisfied, so its relative part is ignored.
+///
+/// The left and right padding can also be [fractional]($fraction). The
+/// horizontal space that the content leaves free is then distributed among
+/// the fractional sides according to their fractions. With `{left: 1fr,
+/// right: 2fr}`, for instance, the content sits a third of the way between
+/// the edges of its container.
+///
+/// ```example
+/// #pad(left: 1fr, right: 2fr)[Off-center]
+/// ```
+///
 /// # Example
 /// ```example
 /// #set align(center)
@@ -22,12 +38,13 @@ use crate::layout::{
 pub struct PadElem {
     /// The padding at the left side.
     #[parse(
-        let all = args.named("rest")?.or(args.find()?);
-        let x = args.named("x")?.or(all);
+        let all = args.named::<Rel<Length>>("rest")?.or(args.find()?);
+        let x = args.named("x")?.or(all.map(Spacing::Rel));
         let y = args.named("y")?.or(all);
         args.named("left")?.or(x)
     )]
-    pub left: Rel<Length>,
+    #[default(Spacing::Rel(Rel::zero()))]
+    pub left: Spacing,
 
     /// The padding at the top side.
     #[parse(args.named("top")?.or(y))]
@@ -35,7 +52,8 @@ pub struct PadElem {
 
     /// The padding at the right side.
     #[parse(args.named("right")?.or(x))]
-    pub right: Rel<Length>,
+    #[default(Spacing::Rel(Rel::zero()))]
+    pub right: Spacing,
 
     /// The padding at the bottom side.
     #[parse(args.named("bottom")?.or(y))]
@@ -44,7 +62,7 @@ pub struct PadElem {
     /// The horizontal padding. Both `left` and `right` take precedence over
     /// this.
     #[external]
-    pub x: Rel<Length>,
+    pub x: Spacing,
 
     /// The vertical padding. Both `top` and `bottom` take precedence over this.
     #[external]
@@ -55,6 +73,34 @@ pub struct PadElem {
     #[external]
     pub rest: Rel<Length>,
 
+    /// Whether the padding affects the size of the content.
+    ///
+    /// If `{false}`, the content is laid out as if there was no padding and
+    /// keeps its size, but it is shifted by the left and top padding. It can
+    /// thus overlap with the content around it.
+    ///
+    /// ```example
+    /// #rect(pad(left: 12pt, top: 4pt, reflow: false)[Nudged])
+    /// #rect(pad(left: 12pt, top: 4pt)[Padded])
+    /// ```
+    #[default(true)]
+    pub reflow: bool,
+
+    /// Whether the top and bottom padding only apply at the edges of content
+    /// that breaks across pages or columns.
+    ///
+    /// By default, every piece of broken content is padded on all sides. If
+    /// `{true}`, the top padding only applies to the first piece and the
+    /// bottom padding only to the last one, while the left and right padding
+    /// still apply to every piece.
+    ///
+    /// ```example
+    /// #set page(height: 60pt)
+    /// #rect(inset: 0pt, pad(y: 8pt, split: true, lorem(12)))
+    /// ```
+    #[default(false)]
+    pub split: bool,
+
     /// The content to pad at the sides.
     #[required]
     pub body: Content,
@@ -68,22 +114,74 @@ impl LayoutMultiple for Packed<PadElem> {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
+        let left = self.left(styles);
+        let right = self.right(styles);
         let sides = Sides::new(
-            self.left(styles),
+            non_fractional(left),
             self.top(styles),
-            self.right(styles),
+            non_fractional(right),
             self.bottom(styles),
         );
 
+        // With fractional padding, the child only takes the width it needs so
+        // that the remaining width can be distributed.
+        let fr = (fractional(left), fractional(right));
+        let width = regions.size.x;
+        let mut regions = regions;
+        if !(fr.0 + fr.1).is_zero() {
+            regions.expand.x = false;
+        }
+
+        let padding = satisfiable(sides.resolve(styles));
+        if !self.reflow(styles) {
+            // Layout the child as is and only shift it.
+            let mut fragment = self.body().layout(engine, styles, regions)?;
+            for frame in &mut fragment {
+                let padding = padding.relative_to(frame.size());
+                frame.translate(Point::new(padding.left, padding.top));
+            }
+            distribute(&mut fragment, fr, width, false);
+            return Ok(fragment);
+        }
+
         // Layout child into padded regions.
-        let mut backlog = vec![];
-        let padding = sides.resolve(styles);
-        let pod = regions.map(&mut backlog, |size| shrink(size, padding));
-        let mut fragment = self.body().layout(engine, styles, pod)?;
+        let body = self.body();
+        let split = self.split(styles);
+        let mut fragment = if split {
+            // The top padding only applies to the first region and the bottom
+            // padding only to the last one. As we don't know in advance how
+            // many regions the child needs, we first don't reserve space for
+            // the bottom padding and only relayout if the last piece turns out
+            // not to leave enough room for it.
+            let mut fragment =
+                layout_padded(engine, body, styles, regions, padding, None)?;
+            if let Some(frame) = fragment.iter().last() {
+                let last = fragment.len() - 1;
+                let available = regions.iter().nth(last).unwrap_or(regions.size).y;
+                let needed = grow(frame.size(), edges(padding, last, true)).y;
+                if !available.fits(needed) {
+                    fragment = layout_padded(
+                        engine,
+                        body,
+                        styles,
+                        regions,
+                        padding,
+                        Some(last),
+                    )?;
+                }
+            }
+            fragment
+        } else {
+            let mut backlog = vec![];
+            let pod = regions.map(&mut backlog, |size| shrink(size, padding));
+            body.layout(engine, styles, pod)?
+        };
 
-        for frame in &mut fragment {
+        let count = fragment.len();
+        for (i, frame) in fragment.iter_mut().enumerate() {
             // Apply the padding inversely such that the grown size padded
             // yields the frame's size.
+            let padding = if split { edges(padding, i, i + 1 == count) } else { padding };
             let padded = grow(frame.size(), padding);
             let padding = padding.relative_to(padded);
             let offset = Point::new(padding.left, padding.top);
@@ -93,13 +191,114 @@ impl LayoutMultiple for Packed<PadElem> {
             frame.translate(offset);
         }
 
+        distribute(&mut fragment, fr, width, true);
         Ok(fragment)
     }
 }
 
+/// The non-fractional part of horizontal padding.
+fn non_fractional(spacing: Spacing) -> Rel<Length> {
+    match spacing {
+        Spacing::Rel(rel) => rel,
+        Spacing::Fr(_) => Rel::zero(),
+    }
+}
+
+/// The fractional part of horizontal padding.
+fn fractional(spacing: Spacing) -> Fr {
+    match spacing {
+        Spacing::Rel(_) => Fr::zero(),
+        Spacing::Fr(fr) => fr,
+    }
+}
+
+/// Distribute the width that the frames leave free in the given `width`
+/// among the fractional left and right padding. If `grow` is true, the
+/// frames are grown to the full width. Otherwise, they are only shifted.
+fn distribute(fragment: &mut Fragment, (left, right): (Fr, Fr), width: Abs, grow: bool) {
+    let total = left + right;
+    if total.is_zero() || !width.is_finite() {
+        return;
+    }
+
+    for frame in fragment {
+        let remaining = (width - frame.width()).max(Abs::zero());
+        let offset = left.share(total, remaining);
+        if grow {
+            frame.size_mut().x += remaining;
+        }
+        frame.translate(Point::with_x(offset));
+    }
+}
+
+/// Layout the body into regions shrunk by the padding that applies to each
+/// of them. The bottom padding is reserved starting at the region with index
+/// `bottom`, if any.
+fn layout_padded(
+    engine: &mut Engine,
+    body: &Content,
+    styles: StyleChain,
+    regions: Regions,
+    padding: Sides<Rel<Abs>>,
+    bottom: Option<usize>,
+) -> SourceResult<Fragment> {
+    let shrunk = |i: usize, height: Abs| {
+        let last = bottom.is_some_and(|bottom| i >= bottom);
+        shrink(Size::new(regions.size.x, height), edges(padding, i, last))
+    };
+
+    let backlog: Vec<Abs> = regions
+        .backlog
+        .iter()
+        .enumerate()
+        .map(|(i, &height)| shrunk(i + 1, height).y)
+        .collect();
+
+    // The final region repeats, so it counts as following all others.
+    let pod = Regions {
+        size: shrink(regions.size, edges(padding, 0, bottom == Some(0))),
+        full: shrunk(0, regions.full).y,
+        backlog: &backlog,
+        last: regions.last.map(|height| shrunk(usize::MAX, height).y),
+        expand: regions.expand,
+        root: false,
+        columns: regions.columns,
+        column: regions.column,
+    };
+
+    body.layout(engine, styles, pod)
+}
+
+/// The padding of the region with index `i`, which only includes the top
+/// padding in the first and the bottom padding in the last region.
+fn edges(padding: Sides<Rel<Abs>>, i: usize, last: bool) -> Sides<Rel<Abs>> {
+    Sides {
+        top: if i == 0 { padding.top } else { Rel::zero() },
+        bottom: if last { padding.bottom } else { Rel::zero() },
+        ..padding
+    }
+}
+
+/// Drop the relative part of the padding along each axis where it adds up to
+/// 100% or more, as no size could then fit both the padding and the content.
+fn satisfiable(mut padding: Sides<Rel<Abs>>) -> Sides<Rel<Abs>> {
+    let sum = padding.sum_by_axis();
+    if sum.x.rel.get() >= 1.0 {
+        padding.left.rel = Ratio::zero();
+        padding.right.rel = Ratio::zero();
+    }
+    if sum.y.rel.get() >= 1.0 {
+        padding.top.rel = Ratio::zero();
+        padding.bottom.rel = Ratio::zero();
+    }
+    padding
+}
+
 /// Shrink a size by padding relative to the size itself.
+///
+/// The result is never negative, even if the padding exceeds the size.
 fn shrink(size: Size, padding: Sides<Rel<Abs>>) -> Size {
-    size - padding.relative_to(size).sum_by_axis()
+    (size - padding.relative_to(size).sum_by_axis()).map(|v| v.max(Abs::zero()))
 }
 
 /// Grow a size by padding relative to the grown size.
@@ -121,6 +320,8 @@ fn shrink(size: Size, padding: Sides<Rel<Abs>>) -> Size {
 ///   <=> w - p.rel * w - p.abs = s
 ///   <=> (1 - p.rel) * w = s + p.abs
 ///   <=> w = (s + p.abs) / (1 - p.rel)
+///
+/// The relative padding must sum to less than 100%, see [`satisfiable`].
 fn grow(size: Size, padding: Sides<Rel<Abs>>) -> Size {
     size.zip_map(padding.sum_by_axis(), |s, p| (s + p.abs) / (1.0 - p.rel.get()))
 }
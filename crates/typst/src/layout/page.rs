@@ -4,12 +4,13 @@ use std::ptr;
 use std::str::FromStr;
 
 use comemo::Track;
+use ecow::{eco_format, EcoString};
 
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, AutoValue, Cast, Content, Context, Dict, Fold, Func, NativeElement,
-    Packed, Resolve, Smart, StyleChain, Value,
+    NoneValue, Packed, Resolve, Smart, Str, StyleChain, Value,
 };
 use crate::introspection::{Counter, CounterDisplayElem, CounterKey, ManualPageCounter};
 use crate::layout::{
@@ -61,6 +62,16 @@ pub struct PageElem {
     ///   box(square(width: 1cm))
     /// }
     /// ```
+    ///
+    /// If this is set to `{auto}`, the page is as wide as its content plus
+    /// the horizontal margins. Together with an `{auto}` height, this
+    /// produces a tight page around a single object, which is useful when
+    /// exporting something like a badge as an image.
+    ///
+    /// ```example
+    /// #set page(width: auto, height: auto, margin: 4pt)
+    /// #rect(fill: aqua)[Sticker]
+    /// ```
     #[resolve]
     #[parse(
         let paper = args.named_or_find::<Paper>("paper")?;
@@ -109,6 +120,8 @@ pub struct PageElem {
     ///
     /// - `{auto}`: The margins are set automatically to 2.5/21 times the smaller
     ///   dimension of the page. This results in 2.5cm margins for an A4 page.
+    ///   If a [content width]($page.content-width) is set, automatic
+    ///   horizontal margins are instead determined by it.
     /// - A single length: The same margin on all sides.
     /// - A dictionary: With a dictionary, the margins can be set individually.
     ///   The dictionary can contain the following keys in order of precedence:
@@ -141,9 +154,106 @@ pub struct PageElem {
     ///   fill: aqua,
     /// )
     /// ```
+    ///
+    /// Both for all margins and, in a dictionary, for individual sides,
+    /// `{none}` means the same as `{auto}`: It resets the margin to the
+    /// default, even if an earlier set rule configured it. To remove a margin
+    /// altogether, set it to `{0pt}` instead.
+    ///
+    /// ```example
+    /// #set page(width: 3cm, height: 2cm, margin: 0pt)
+    /// #set page(margin: (top: none))
+    /// #rect(width: 100%, height: 100%, fill: aqua)
+    /// ```
     #[fold]
     pub margin: Margin,
 
+    /// The desired width of the page's content area.
+    ///
+    /// When set, the horizontal margins that are `{auto}` are no longer
+    /// derived from the page size. Instead, they take up the space that is
+    /// left over after subtracting the content width and any explicitly set
+    /// horizontal margin from the page width. If both are `{auto}`, the
+    /// content area is thus centered on the page. This is useful when only
+    /// the measure of the text block matters.
+    ///
+    /// ```example
+    /// #set page(
+    ///   width: 120pt,
+    ///   height: 40pt,
+    ///   margin: (x: auto, y: 8pt),
+    ///   content-width: 60pt,
+    /// )
+    ///
+    /// #rect(width: 100%, height: 100%, fill: aqua)
+    /// ```
+    pub content_width: Smart<Rel<Length>>,
+
+    /// How much space to leave free at the top of the first page's content
+    /// area.
+    ///
+    /// Only the first page of a run of pages with equal properties is
+    /// affected, all following pages use the full content area. This is
+    /// useful to leave room for a manually [placed]($place) title at the
+    /// start of a document or section. The inset is limited to the height of
+    /// the content area, so content that doesn't fit below it moves to the
+    /// next page.
+    ///
+    /// ```example
+    /// #set page(height: 80pt, first-page-inset: 24pt)
+    /// #place(top, dy: -20pt)[*Title*]
+    /// #lorem(20)
+    /// ```
+    #[resolve]
+    pub first_page_inset: Length,
+
+    /// Overrides for the first page, like a title page without a header.
+    ///
+    /// A dictionary that may contain a `header`, a `footer`, and a `margin`
+    /// with `top` and `bottom` keys. Its entries replace the respective page
+    /// properties on the first page only. The following pages revert to the
+    /// regular settings.
+    ///
+    /// The overrides are applied only once, to the first page after the set
+    /// rule that specified them. The pages after a [page break]($pagebreak)
+    /// or after a set rule that changes other page properties use the
+    /// regular settings. To apply the overrides again, for instance at the
+    /// start of the next chapter, repeat the set rule, even with the same
+    /// values. A page created with the `page` function applies its own
+    /// overrides to its first page.
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 80pt,
+    ///   header: [_Report_],
+    ///   first: (header: none, margin: (top: 12pt)),
+    /// )
+    ///
+    /// *Title page* \
+    /// #lorem(4)
+    /// #pagebreak()
+    /// #lorem(4)
+    /// ```
+    #[borrowed]
+    pub first: Option<FirstPage>,
+
+    /// How to align the page's content as a whole within the content area.
+    ///
+    /// By default, content starts at the top-left (or top-right, for
+    /// right-to-left text) of the area inside the margins. With a vertical
+    /// alignment, each page's content is instead pushed down to the center or
+    /// the bottom margin, which is useful for title pages and colophons. The
+    /// content keeps its own layout and only moves as one block.
+    ///
+    /// ```example
+    /// #set page(height: 100pt, align: bottom)
+    /// #set text(size: 8pt)
+    /// *Colophon* \
+    /// Typeset with Typst.
+    /// ```
+    #[default]
+    pub align: Alignment,
+
     /// On which side the pages will be bound.
     ///
     /// - `{auto}`: Equivalent to `left` if the [text direction]($text.dir)
@@ -190,6 +300,29 @@ pub struct PageElem {
     #[borrowed]
     pub fill: Option<Paint>,
 
+    /// Whether to snap the positions of the page's contents to a pixel grid.
+    ///
+    /// When a page is exported as a raster image, items that sit between two
+    /// pixels are blurred across both. Thin rules, like the lines of a table,
+    /// then look washed out. When enabled, the positions of all items as well
+    /// as the sizes of shapes and images are rounded to whole pixels at the
+    /// page's [`dpi`]($page.dpi). This only affects the finished pages and
+    /// not the layout itself. Content inside of [rotated]($rotate) or
+    /// [scaled]($scale) elements is not snapped.
+    ///
+    /// ```example
+    /// #set page(snap-to-pixel: true, dpi: 144)
+    /// #table(columns: 3, [A], [B], [C])
+    /// ```
+    #[default(false)]
+    pub snap_to_pixel: bool,
+
+    /// The resolution in dots per inch of the pixel grid that the page's
+    /// contents are snapped to when [`snap-to-pixel`]($page.snap-to-pixel) is
+    /// enabled. This should match the resolution used for export.
+    #[default(NonZeroUsize::new(144).unwrap())]
+    pub dpi: NonZeroUsize,
+
     /// How to [number]($numbering) the pages.
     ///
     /// If an explicit `footer` (or `header` for top-aligned numbering) is
@@ -241,6 +374,30 @@ pub struct PageElem {
     ///
     /// #lorem(19)
     /// ```
+    ///
+    /// The header is laid out in an area that is as wide as the page's
+    /// content, i.e. the page width minus the left and right margins, and
+    /// reaches from the top edge of the page down to the
+    /// [`header-ascent`]($page.header-ascent) above the content. Its origin
+    /// is thus horizontally aligned with the content's left edge. Within the
+    /// header, the [`layout`] function provides the exact size of this area,
+    /// which lets you align parts of the header precisely with the content.
+    /// The same holds for the [footer]($page.footer), which starts at the
+    /// [`footer-descent`]($page.footer-descent) below the content and
+    /// reaches down to the bottom edge of the page.
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 80pt,
+    ///   margin: (x: 24pt, top: 32pt),
+    ///   header: layout(size => [
+    ///     #set text(8pt)
+    ///     Header area: #size.width × #size.height
+    ///   ]),
+    /// )
+    ///
+    /// #lorem(8)
+    /// ```
     #[borrowed]
     pub header: Option<Content>,
 
@@ -249,6 +406,28 @@ pub struct PageElem {
     #[default(Ratio::new(0.3).into())]
     pub header_ascent: Rel<Length>,
 
+    /// The horizontal alignment of the header.
+    ///
+    /// In addition to the usual horizontal alignments, this can be
+    /// `{"inside"}` or `{"outside"}` to align the header towards or away
+    /// from the page's [binding]($page.binding). These flip between left and
+    /// right from one page to the next, which is the common convention for
+    /// running heads in books. If set to `{auto}`, the header follows the
+    /// surrounding [alignment]($align).
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 60pt,
+    ///   header: [_Chapter 1_],
+    ///   header-align: "outside",
+    /// )
+    ///
+    /// #lorem(4)
+    /// #pagebreak()
+    /// #lorem(4)
+    /// ```
+    pub header_align: Smart<MarginalAlignment>,
+
     /// The page's footer. Fills the bottom margin of each page.
     ///
     /// For just a page number, the `numbering` property, typically suffices. If
@@ -280,6 +459,11 @@ pub struct PageElem {
     #[default(Ratio::new(0.3).into())]
     pub footer_descent: Rel<Length>,
 
+    /// The horizontal alignment of the footer. Like the
+    /// [`header-align`]($page.header-align), this can also be `{"inside"}` or
+    /// `{"outside"}`.
+    pub footer_align: Smart<MarginalAlignment>,
+
     /// Content in the page's background.
     ///
     /// This content will be placed behind the page's body. It can be
@@ -325,6 +509,12 @@ pub struct PageElem {
     #[internal]
     #[synthesized]
     pub clear_to: Option<Parity>,
+
+    /// Whether the first page overrides were already applied to an earlier
+    /// run of pages.
+    #[internal]
+    #[synthesized]
+    pub first_used: bool,
 }
 
 impl Packed<PageElem> {
@@ -341,6 +531,7 @@ impl Packed<PageElem> {
         styles: StyleChain,
         page_counter: &mut ManualPageCounter,
         extend_to: Option<Parity>,
+        apply_first: bool,
     ) -> SourceResult<Vec<Page>> {
         // When one of the lengths is infinite the page fits its content along
         // that axis.
@@ -360,12 +551,49 @@ impl Packed<PageElem> {
         let default = Rel::<Length>::from((2.5 / 21.0) * min);
         let margin = self.margin(styles);
         let two_sided = margin.two_sided.unwrap_or(false);
-        let margin = margin
-            .sides
-            .map(|side| side.and_then(Smart::custom).unwrap_or(default))
+        let sides = margin.sides.map(|side| side.unwrap_or(Smart::Auto));
+        let mut margin = sides
+            .map(|side| side.unwrap_or(default))
             .resolve(styles)
             .relative_to(size);
 
+        // Let automatic horizontal margins take up the space left over by
+        // the requested content width.
+        if let Smart::Custom(content_width) = self.content_width(styles) {
+            let content_width = content_width.resolve(styles).relative_to(size.x);
+            let leftover = size.x - content_width;
+            if leftover.is_finite() {
+                match (sides.left.is_auto(), sides.right.is_auto()) {
+                    (true, true) => {
+                        margin.left = (leftover / 2.0).max(Abs::zero());
+                        margin.right = margin.left;
+                    }
+                    (true, false) => {
+                        margin.left = (leftover - margin.right).max(Abs::zero());
+                    }
+                    (false, true) => {
+                        margin.right = (leftover - margin.left).max(Abs::zero());
+                    }
+                    (false, false) => {}
+                }
+            }
+        }
+
+        // Determine the first page's margins.
+        let first = if apply_first { self.first(styles).as_ref() } else { None };
+        let mut first_margin = margin;
+        if let Some(sides) = first.and_then(|first| first.margin) {
+            let resolve = |side: Smart<Rel<Length>>| {
+                side.unwrap_or(default).resolve(styles).relative_to(size.y)
+            };
+            if let Some(top) = sides.top {
+                first_margin.top = resolve(top);
+            }
+            if let Some(bottom) = sides.bottom {
+                first_margin.bottom = resolve(bottom);
+            }
+        }
+
         // Determine the binding.
         let binding =
             self.binding(styles)
@@ -388,9 +616,33 @@ impl Packed<PageElem> {
         let mut regions = Regions::repeat(area, area.map(Abs::is_finite));
         regions.root = true;
 
+        // Reserve the inset and the override margins on the first page.
+        let first_page_inset = self.first_page_inset(styles).min(area.y).max(Abs::zero());
+        regions.size.y -= first_page_inset;
+        regions.size.y -= first_margin.sum_by_axis().y - margin.sum_by_axis().y;
+        let first_height = regions.size.y;
+
+        // Let the content hug its size along the axes it is aligned on.
+        let align = self.align(styles);
+        let hug = Axes::new(
+            align.x().is_some_and(|x| x != HAlignment::Start),
+            align.y().is_some_and(|y| y != VAlignment::Top),
+        ) & area.map(Abs::is_finite);
+        regions.expand &= hug.map(|hug| !hug);
+
         // Layout the child.
         let mut frames = child.layout(engine, styles, regions)?.into_frames();
 
+        // Align the content within the content area.
+        if hug.any(|&hug| hug) {
+            let fixed = align.resolve(styles);
+            for (i, frame) in frames.iter_mut().enumerate() {
+                let height = if i == 0 { first_height } else { area.y };
+                let target = hug.select(Size::new(area.x, height), frame.size());
+                frame.resize(target, fixed);
+            }
+        }
+
         // Align the child to the pagebreak's parity.
         // Check for page count after adding the pending frames
         if extend_to
@@ -402,10 +654,17 @@ impl Packed<PageElem> {
         }
 
         let fill = self.fill(styles);
+
+        // The pixel grid to snap to, if any.
+        let snap = self
+            .snap_to_pixel(styles)
+            .then(|| Abs::inches(1.0) / self.dpi(styles).get() as f64);
         let foreground = Cow::Borrowed(self.foreground(styles));
         let background = Cow::Borrowed(self.background(styles));
         let header_ascent = self.header_ascent(styles);
         let footer_descent = self.footer_descent(styles);
+        let header_align = self.header_align(styles);
+        let footer_align = self.footer_align(styles);
         let numbering = self.numbering(styles);
         let number_align = self.number_align(styles);
         let mut header = Cow::Borrowed(self.header(styles));
@@ -443,15 +702,22 @@ impl Packed<PageElem> {
 
         // Post-process pages.
         let mut pages = Vec::with_capacity(frames.len());
-        for mut frame in frames {
+        for (i, mut frame) in frames.into_iter().enumerate() {
             // The padded width of the page's content without margins.
             let pw = frame.width();
 
+            // Move the first page's content below its inset.
+            if i == 0 && !first_page_inset.is_zero() {
+                frame.size_mut().y += first_page_inset;
+                frame.translate(Point::with_y(first_page_inset));
+            }
+
             // If two sided, left becomes inside and right becomes outside.
             // Thus, for left-bound pages, we want to swap on even pages and
             // for right-bound pages, we want to swap on odd pages.
-            let mut margin = margin;
-            if two_sided && binding.swap(page_counter.physical()) {
+            let swap = binding.swap(page_counter.physical());
+            let mut margin = if i == 0 { first_margin } else { margin };
+            if two_sided && swap {
                 std::mem::swap(&mut margin.left, &mut margin.right);
             }
 
@@ -462,6 +728,17 @@ impl Packed<PageElem> {
             // The page size with margins.
             let size = frame.size();
 
+            // Apply the first page's marginal overrides.
+            let first = first.filter(|_| i == 0);
+            let header = match first.and_then(|first| first.header.as_ref()) {
+                Some(content) => Cow::Borrowed(content),
+                None => header.clone(),
+            };
+            let footer = match first.and_then(|first| first.footer.as_ref()) {
+                Some(content) => Cow::Borrowed(content),
+                None => footer.clone(),
+            };
+
             // Realize overlays.
             for marginal in [&header, &footer, &background, &foreground] {
                 let Some(content) = &**marginal else { continue };
@@ -471,12 +748,18 @@ impl Packed<PageElem> {
                     let ascent = header_ascent.relative_to(margin.top);
                     pos = Point::with_x(margin.left);
                     area = Size::new(pw, margin.top - ascent);
-                    align = Alignment::BOTTOM;
+                    align = match header_align {
+                        Smart::Custom(x) => x.resolve(swap) + VAlignment::Bottom,
+                        Smart::Auto => Alignment::BOTTOM,
+                    };
                 } else if ptr::eq(marginal, &footer) {
                     let descent = footer_descent.relative_to(margin.bottom);
                     pos = Point::new(margin.left, size.y - margin.bottom + descent);
                     area = Size::new(pw, margin.bottom - descent);
-                    align = Alignment::TOP;
+                    align = match footer_align {
+                        Smart::Custom(x) => x.resolve(swap) + VAlignment::Top,
+                        Smart::Auto => Alignment::TOP,
+                    };
                 } else {
                     pos = Point::zero();
                     area = size;
@@ -501,6 +784,10 @@ impl Packed<PageElem> {
                 frame.fill(fill.clone());
             }
 
+            if let Some(unit) = snap {
+                frame.snap(unit);
+            }
+
             page_counter.visit(engine, &frame)?;
             pages.push(Page {
                 frame,
@@ -508,6 +795,7 @@ impl Packed<PageElem> {
                 number: page_counter.logical(),
             });
 
+            engine.tracer.progress(page_counter.physical().get());
             page_counter.step();
         }
 
@@ -592,9 +880,19 @@ cast! {
         Value::Dict(dict)
     },
     _: AutoValue => Self::splat(Some(Smart::Auto)),
+    _: NoneValue => Self::splat(Some(Smart::Auto)),
     v: Rel<Length> => Self::splat(Some(Smart::Custom(v))),
     mut dict: Dict => {
-        let mut take = |key| dict.take(key).ok().map(Value::cast).transpose();
+        // A side set to `none` is reset to the default, just like `auto`.
+        let mut take = |key| {
+            dict.take(key)
+                .ok()
+                .map(|v| match v {
+                    Value::None => Ok(Smart::Auto),
+                    v => v.cast(),
+                })
+                .transpose()
+        };
 
         let rest = take("rest")?;
         let x = take("x")?.or(rest);
@@ -637,6 +935,52 @@ cast! {
     }
 }
 
+/// Overrides for the first page of a run of pages.
+#[derive(Debug, Default, Clone, PartialEq, Hash)]
+pub struct FirstPage {
+    /// The first page's top and bottom margins.
+    pub margin: Option<Sides<Option<Smart<Rel<Length>>>>>,
+    /// The first page's header.
+    pub header: Option<Option<Content>>,
+    /// The first page's footer.
+    pub footer: Option<Option<Content>>,
+}
+
+cast! {
+    FirstPage,
+    self => {
+        let mut dict = Dict::new();
+        if let Some(sides) = self.margin {
+            let mut margin = Dict::new();
+            if let Some(top) = sides.top {
+                margin.insert("top".into(), top.into_value());
+            }
+            if let Some(bottom) = sides.bottom {
+                margin.insert("bottom".into(), bottom.into_value());
+            }
+            dict.insert("margin".into(), margin.into_value());
+        }
+        if let Some(header) = self.header {
+            dict.insert("header".into(), header.into_value());
+        }
+        if let Some(footer) = self.footer {
+            dict.insert("footer".into(), footer.into_value());
+        }
+        dict.into_value()
+    },
+    mut dict: Dict => {
+        let margin: Option<Margin> =
+            dict.take("margin").ok().map(Value::cast).transpose()?;
+        if margin.is_some_and(|m| m.sides.left.is_some() || m.sides.right.is_some()) {
+            bail!("the first page can only override the top and bottom margins");
+        }
+        let header = dict.take("header").ok().map(Value::cast).transpose()?;
+        let footer = dict.take("footer").ok().map(Value::cast).transpose()?;
+        dict.finish(&["margin", "header", "footer"])?;
+        Self { margin: margin.map(|m| m.sides), header, footer }
+    },
+}
+
 /// Specification of the page's binding.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Binding {
@@ -673,6 +1017,41 @@ cast! {
     },
 }
 
+/// The horizontal alignment of a page's header or footer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MarginalAlignment {
+    /// A fixed horizontal alignment.
+    Align(HAlignment),
+    /// Towards the page's binding.
+    Inside,
+    /// Away from the page's binding.
+    Outside,
+}
+
+impl MarginalAlignment {
+    /// Resolve to a fixed alignment, given whether the inside and outside of
+    /// the page are swapped compared to its binding (see [`Binding::swap`]).
+    fn resolve(self, swap: bool) -> HAlignment {
+        match (self, swap) {
+            (Self::Align(align), _) => align,
+            (Self::Inside, false) | (Self::Outside, true) => HAlignment::Left,
+            (Self::Inside, true) | (Self::Outside, false) => HAlignment::Right,
+        }
+    }
+}
+
+cast! {
+    MarginalAlignment,
+    self => match self {
+        Self::Align(align) => align.into_value(),
+        Self::Inside => "inside".into_value(),
+        Self::Outside => "outside".into_value(),
+    },
+    "inside" => Self::Inside,
+    "outside" => Self::Outside,
+    align: HAlignment => Self::Align(align),
+}
+
 /// A header, footer, foreground or background definition.
 #[derive(Debug, Clone, Hash)]
 pub enum Marginal {
@@ -712,7 +1091,11 @@ cast! {
 
 /// A manual page break.
 ///
-/// Must not be used inside any containers.
+/// Inside of a breakable [block]($block), the break continues the block on
+/// the next page. It must not be used inside of any other containers. Just
+/// like a [paragraph break]($parbreak), it is regular content that can be
+/// joined with other content, e.g. `{[Title] + pagebreak() + body}`. The
+/// break takes effect wherever the joined content ends up in the document.
 ///
 /// # Example
 /// ```example
@@ -785,6 +1168,77 @@ impl Paper {
     }
 }
 
+/// Parses a paper from its name.
+///
+/// Matching is case-insensitive and treats spaces and underscores like
+/// hyphens. Some papers are also known under a common alias, like `letter`
+/// for `us-letter`.
+impl FromStr for Paper {
+    type Err = EcoString;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let normalized = name.trim().to_lowercase().replace([' ', '_'], "-");
+        Self::from_name(&normalized)
+            .or_else(|| {
+                PAPER_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == normalized)
+                    .map(|&(_, paper)| paper)
+            })
+            .ok_or_else(|| match suggest_paper(&normalized) {
+                Some(suggestion) => eco_format!(
+                    "unknown paper size \"{name}\"; did you mean \"{suggestion}\"?"
+                ),
+                None => eco_format!("unknown paper size \"{name}\""),
+            })
+    }
+}
+
+/// Find the known paper name that is closest to a misspelled one.
+fn suggest_paper(name: &str) -> Option<&'static str> {
+    // The most common papers come first so that they win ties.
+    ["a4", "us-letter"]
+        .into_iter()
+        .chain(PAPER_ALIASES.iter().map(|&(alias, _)| alias))
+        .chain(Paper::NAMES.iter().copied())
+        .map(|candidate| (candidate, edit_distance(name, candidate)))
+        .filter(|&(_, distance)| distance <= 2 && distance < name.chars().count())
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] =
+                if ca == cb { diagonal } else { 1 + diagonal.min(row[j]).min(above) };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Common alternative names of predefined papers.
+const PAPER_ALIASES: &[(&str, Paper)] = &[
+    ("letter", Paper::US_LETTER),
+    ("legal", Paper::US_LEGAL),
+    ("tabloid", Paper::US_TABLOID),
+    ("ledger", Paper::US_LEDGER),
+    ("executive", Paper::US_EXECUTIVE),
+    ("b4", Paper::ISO_B4),
+    ("b5", Paper::ISO_B5),
+    ("b6", Paper::ISO_B6),
+    ("c4", Paper::ISO_C4),
+    ("c5", Paper::ISO_C5),
+    ("c6", Paper::ISO_C6),
+];
+
 /// Defines paper constants and a paper parsing implementation.
 macro_rules! papers {
     ($(($var:ident: $width:expr, $height: expr, $name:literal))*) => {
@@ -797,15 +1251,15 @@ macro_rules! papers {
                 width: Scalar::new($width),
                 height: Scalar::new($height),
             };)*
-        }
 
-        impl FromStr for Paper {
-            type Err = &'static str;
+            /// The names of all predefined papers.
+            const NAMES: &'static [&'static str] = &[$($name),*];
 
-            fn from_str(name: &str) -> Result<Self, Self::Err> {
-                match name.to_lowercase().as_str() {
-                    $($name => Ok(Self::$var),)*
-                    _ => Err("unknown paper size"),
+            /// Find a paper by its exact kebab-case name.
+            fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(Self::$var),)*
+                    _ => None,
                 }
             }
         }
@@ -817,6 +1271,7 @@ macro_rules! papers {
                 /// Produces a paper of the respective size.
                 $name => Self::$var,
             )*
+            v: Str => v.parse()?,
         }
     };
 }
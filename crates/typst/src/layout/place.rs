@@ -80,8 +80,36 @@ pub struct PlaceElem {
     pub dx: Rel<Length>,
 
     /// The vertical displacement of the placed content.
+    ///
+    /// Both displacements may be negative and can move the content partly or
+    /// fully outside of its container. The content is still positioned
+    /// relative to its alignment.
+    ///
+    /// ```example
+    /// #block(width: 60pt, height: 40pt, stroke: gray)[
+    ///   #place(top + right, dx: 8pt, dy: -8pt, circle(radius: 8pt, fill: red))
+    /// ]
+    /// ```
     pub dy: Rel<Length>,
 
+    /// Whether to clip the placed content to the bounds of its container.
+    ///
+    /// By default, content that is displaced or too large for its container
+    /// extends beyond it.
+    ///
+    /// ```example
+    /// #block(width: 60pt, height: 40pt, stroke: gray)[
+    ///   #place(
+    ///     top + right,
+    ///     dx: 8pt,
+    ///     dy: -8pt,
+    ///     clip: true,
+    ///     circle(radius: 8pt, fill: red),
+    ///   )
+    /// ]
+    /// ```
+    pub clip: bool,
+
     /// The content to place.
     #[required]
     pub body: Content,
@@ -0,0 +1,88 @@
+use comemo::Tracked;
+
+use crate::diag::{bail, At, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, dict, elem, func, Args, Construct, Content, Context, NativeElement, Packed,
+    StyleChain,
+};
+use crate::layout::{Abs, Axes, BoxElem, Frame, LayoutMultiple, LayoutSingle, Regions};
+use crate::syntax::Span;
+use crate::util::hash128;
+
+/// Lays out content ahead of time.
+///
+/// The `prerender` function lays out content into a standalone tile and
+/// returns it wrapped in a [box]. Placing the tile multiple times reuses the
+/// finished layout instead of laying out the content again.
+///
+/// Like with [`measure`], the content is laid out in an infinite space, using
+/// the styles at the location of the `prerender` call. Styles applied later
+/// around the tile don't affect its contents anymore.
+///
+/// # Example
+/// ```example
+/// #context {
+///   let tile = prerender(rect(fill: aqua)[Tile])
+///   stack(dir: ltr, spacing: 4pt, tile, tile, tile)
+/// }
+/// ```
+///
+/// Since the same tile can be placed several times, it should not contain
+/// elements that you want to [query] or reference.
+#[func(contextual)]
+pub fn prerender(
+    /// The engine.
+    engine: &mut Engine,
+    /// The callsite context.
+    context: Tracked<Context>,
+    /// The callsite span.
+    span: Span,
+    /// The content to lay out.
+    content: Content,
+) -> SourceResult<Content> {
+    let styles = context.styles().at(span)?;
+    let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+    let frame = content.layout(engine, styles, pod)?.into_frame();
+    let tile = PrerenderedElem::new(Prerendered(frame)).pack().spanned(span);
+    Ok(BoxElem::new().with_body(Some(tile)).pack().spanned(span))
+}
+
+/// A tile produced by [`prerender`].
+#[elem(Construct, LayoutSingle)]
+pub struct PrerenderedElem {
+    /// The finished frame.
+    #[required]
+    #[internal]
+    pub frame: Prerendered,
+}
+
+impl Construct for PrerenderedElem {
+    fn construct(_: &mut Engine, args: &mut Args) -> SourceResult<Content> {
+        bail!(args.span, "cannot be constructed manually");
+    }
+}
+
+impl LayoutSingle for Packed<PrerenderedElem> {
+    fn layout(&self, _: &mut Engine, _: StyleChain, _: Regions) -> SourceResult<Frame> {
+        Ok(self.frame().0.clone())
+    }
+}
+
+/// A frame that was laid out ahead of time.
+#[derive(Debug, Clone, Hash)]
+pub struct Prerendered(pub Frame);
+
+impl PartialEq for Prerendered {
+    fn eq(&self, other: &Self) -> bool {
+        hash128(&self.0) == hash128(&other.0)
+    }
+}
+
+cast! {
+    Prerendered,
+    self => {
+        let size = self.0.size();
+        dict! { "width" => size.x, "height" => size.y }.into_value()
+    },
+}
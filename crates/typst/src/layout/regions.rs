@@ -22,6 +22,11 @@ pub struct Regions<'a> {
     /// True for the padded page regions and columns directly in the page,
     /// false otherwise.
     pub root: bool,
+    /// How many consecutive regions form the columns of one parent region,
+    /// for example of a page. This is one outside of columns.
+    pub columns: usize,
+    /// The index of the first region among the columns of its parent region.
+    pub column: usize,
 }
 
 impl Regions<'_> {
@@ -34,6 +39,8 @@ impl Regions<'_> {
             last: None,
             expand,
             root: false,
+            columns: 1,
+            column: 0,
         }
     }
 
@@ -46,6 +53,8 @@ impl Regions<'_> {
             last: Some(size.y),
             expand,
             root: false,
+            columns: 1,
+            column: 0,
         }
     }
 
@@ -75,6 +84,8 @@ impl Regions<'_> {
             last: self.last.map(|y| f(Size::new(x, y)).y),
             expand: self.expand,
             root: false,
+            columns: self.columns,
+            column: self.column,
         }
     }
 
@@ -108,6 +119,7 @@ impl Regions<'_> {
         {
             self.size.y = height;
             self.full = height;
+            self.column = (self.column + 1) % self.columns;
         }
     }
 
@@ -25,6 +25,28 @@ use crate::util::Numeric;
 /// A relative length has the following fields:
 /// - `length`: Its length component.
 /// - `ratio`: Its ratio component.
+///
+/// # Relative to what?
+/// The ratio component is resolved against the size of the surrounding
+/// container along the same axis. This is the same for all layout functions:
+///
+/// - The [`width`]($box.width) and [`height`]($box.height) of boxes and
+///   blocks, the [tracks]($grid.columns) and [gutters]($grid.gutter) of grids,
+///   and [`v`]($v) spacing resolve against the container's width or height,
+///   respectively. On a page, this is the page's size minus its margins.
+/// - [`h`]($h) spacing resolves against the width of the paragraph it is in,
+///   which is the width of its container.
+/// - [Padding]($pad) resolves against the size of the padded content
+///   including the padding. For content that fills its container, this is
+///   again the container's size.
+///
+/// ```example
+/// #set page(width: 120pt, height: auto)
+/// #box(width: 50% - 10pt, fill: aqua)[Box] \
+/// #h(50% - 10pt)H \
+/// #grid(columns: (50% - 10pt, 1fr), fill: yellow)[Grid][]
+/// #pad(left: 50% - 10pt)[Pad]
+/// ```
 #[ty(cast, name = "relative", title = "Relative Length")]
 #[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Rel<T: Numeric = Length> {
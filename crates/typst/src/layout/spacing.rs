@@ -1,4 +1,4 @@
-use crate::foundations::{cast, elem, Content, Packed, Resolve, StyleChain};
+use crate::foundations::{cast, elem, Content, Packed, Resolve, Smart, StyleChain};
 use crate::layout::{Abs, Em, Fr, Length, Ratio, Rel};
 use crate::realize::{Behave, Behaviour};
 use crate::util::Numeric;
@@ -22,7 +22,17 @@ use crate::util::Numeric;
 #[elem(title = "Spacing (H)", Behave)]
 pub struct HElem {
     /// How much spacing to insert.
+    ///
+    /// Passing `{auto}` is equivalent to `{1fr}`: The spacing absorbs the
+    /// remaining space on the line, sharing it equally with other `{auto}` or
+    /// `{1fr}` spacings.
+    ///
+    /// ```example
+    /// Left #h(auto) Right \
+    /// A #h(auto) B #h(auto) C
+    /// ```
     #[required]
+    #[parse(args.expect::<Smart<Spacing>>("amount")?.unwrap_or(Fr::one().into()))]
     pub amount: Spacing,
 
     /// If `{true}`, the spacing collapses at the start or end of a paragraph.
@@ -107,7 +117,24 @@ impl Behave for Packed<HElem> {
 #[elem(title = "Spacing (V)", Behave)]
 pub struct VElem {
     /// How much spacing to insert.
+    ///
+    /// Passing `{auto}` is equivalent to `{1fr}`: The spacing absorbs the
+    /// remaining space in the region, sharing it equally with other `{auto}`
+    /// or `{1fr}` spacings.
+    ///
+    /// Lengths in `em` are resolved against the [text size]($text.size) in
+    /// effect where the spacing is inserted, so spacing like `{0.5em}` keeps
+    /// a vertical rhythm that scales with the text. Changing the text size
+    /// further down doesn't affect spacing that came before. The same holds
+    /// for horizontal spacing.
+    ///
+    /// ```example
+    /// A #v(1em) B
+    /// #set text(size: 16pt)
+    /// C #v(1em) D
+    /// ```
     #[required]
+    #[parse(args.expect::<Smart<Spacing>>("amount")?.unwrap_or(Fr::one().into()))]
     pub amount: Spacing,
 
     /// If `{true}`, the spacing collapses at the start or end of a flow.
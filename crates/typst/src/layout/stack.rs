@@ -1,11 +1,13 @@
 use std::fmt::{self, Debug, Formatter};
 
-use crate::diag::SourceResult;
+use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
-use crate::foundations::{cast, elem, Content, Packed, Resolve, StyleChain, StyledElem};
+use crate::foundations::{
+    cast, elem, Cast, Content, Packed, Resolve, Smart, StyleChain, StyledElem,
+};
 use crate::layout::{
-    Abs, AlignElem, Axes, Axis, Dir, FixedAlignment, Fr, Fragment, Frame, HElem,
-    LayoutMultiple, Point, Regions, Size, Spacing, VElem,
+    Abs, AlignElem, Alignment, Axes, Axis, Dir, FixedAlignment, Fr, Fragment, Frame,
+    HElem, LayoutMultiple, Length, Point, Regions, Rel, Size, Spacing, VElem,
 };
 use crate::util::{Get, Numeric};
 
@@ -23,6 +25,31 @@ use crate::util::{Get, Numeric};
 ///   rect(width: 90pt),
 /// )
 /// ```
+///
+/// # Positions of items
+/// To draw connectors between the items of a stack, you can mark each item
+/// with a labelled [`metadata`]($metadata) and [`locate`]($locate) it after
+/// layout. Combined with [`measure`]($measure), this yields each item's
+/// origin and size on the page.
+///
+/// ```example
+/// #let node(name, body) = box(stroke: 0.5pt, inset: 4pt)[
+///   #metadata(name)<node>#body
+/// ]
+///
+/// #stack(
+///   dir: ltr,
+///   spacing: 20pt,
+///   node("a")[Start],
+///   node("b")[Finish],
+/// )
+///
+/// #context {
+///   let nodes = query(<node>)
+///   let (a, b) = nodes.map(it => it.location().position())
+///   [From #a.x to #b.x]
+/// }
+/// ```
 #[elem(LayoutMultiple)]
 pub struct StackElem {
     /// The direction along which the items are stacked. Possible values are:
@@ -40,14 +67,174 @@ pub struct StackElem {
     ///
     /// For example, `{ttb.start()}` is `top`, `{ttb.end()}` is `bottom`,
     /// `{ttb.axis()}` is `{"vertical"}` and `{ttb.inv()}` is equal to `btt`.
+    ///
+    /// As directions are regular values, they can also be computed, for
+    /// instance to lay out the same items differently depending on a flag.
+    ///
+    /// ```example
+    /// #let compact = true
+    /// #stack(
+    ///   dir: if compact { ltr } else { ttb },
+    ///   spacing: 4pt,
+    ///   rect[A], rect[B],
+    /// )
+    /// ```
     #[default(Dir::TTB)]
     pub dir: Dir,
 
     /// Spacing to insert between items where no explicit spacing was provided.
+    ///
+    /// Items without any size, like an empty template, don't get spacing
+    /// around them. Thus, they don't lead to doubled gaps.
+    ///
+    /// If the spacing is [fractional]($fraction), the gaps between the items
+    /// are elastic: They share all leftover space along the stacking axis
+    /// equally, spreading the items across the full extent of the stack. This
+    /// requires the stack's size along its axis to be known, either because
+    /// it is set explicitly or given by the container. In an auto-sized
+    /// container, fractional gaps collapse to zero.
+    ///
+    /// Since the stack then fills its axis, [alignment]($align) along the
+    /// stacking axis has no effect. Alignment along the other axis still
+    /// positions each item within the stack's cross size.
+    ///
+    /// ```example
+    /// #set rect(width: 20pt, height: 10pt)
+    /// #block(width: 100%, height: 30pt, stroke: gray)[
+    ///   #stack(dir: ltr, spacing: 1fr, rect(), align(bottom, rect()), rect())
+    /// ]
+    /// ```
+    #[parse(
+        let gap = args.named("gap")?;
+        args.named("spacing")?.or(gap)
+    )]
     pub spacing: Option<Spacing>,
 
+    /// An alias for [`spacing`]($stack.spacing), matching the name of the
+    /// corresponding [grid]($grid.gap) argument. If both are given,
+    /// `spacing` takes precedence.
+    ///
+    /// ```example
+    /// #stack(dir: ltr, gap: 4pt, rect(), rect())
+    /// ```
+    #[external]
+    pub gap: Option<Spacing>,
+
+    /// How to distribute leftover space along the stacking axis.
+    ///
+    /// By default, the items are packed at the start of the stack. The other
+    /// modes make the stack fill the available space along its axis and
+    /// insert equal gaps between the items. They have no effect if the stack
+    /// contains fractional spacing, as that already takes up all leftover
+    /// space.
+    ///
+    /// The items keep their order along the stacking direction. In an `rtl`
+    /// stack, the first item is thus placed at the right end and the gaps
+    /// are distributed from right to left.
+    ///
+    /// ```example
+    /// #set rect(width: 30pt, height: 10pt)
+    /// #for mode in ("space-between", "space-around", "space-evenly") {
+    ///   stack(dir: ltr, justify: mode, rect(), rect(), rect())
+    /// }
+    /// ```
+    #[default(StackJustify::Start)]
+    pub justify: StackJustify,
+
+    /// How to combine spacing children that directly follow each other.
+    ///
+    /// By default, consecutive spacings add up. With `{"max"}`, only the
+    /// largest of them is inserted, like with weak spacing in a paragraph.
+    /// Fractional spacing counts as larger than any fixed spacing.
+    ///
+    /// ```example
+    /// #set rect(width: 40pt, height: 8pt)
+    /// #stack(dir: ltr, spacing: 10pt,
+    ///   stack(rect(), v(8pt), v(4pt), rect()),
+    ///   stack(collapse: "max", rect(), v(8pt), v(4pt), rect()),
+    /// )
+    /// ```
+    #[default(StackCollapse::Sum)]
+    pub collapse: StackCollapse,
+
+    /// The default alignment of the items along the stack's cross axis, that
+    /// is, horizontally in a vertical stack and vertically in a horizontal
+    /// one.
+    ///
+    /// If set to `{auto}`, the items follow the surrounding
+    /// [alignment]($align). Items that are wrapped in their own `align` keep
+    /// their alignment.
+    ///
+    /// ```example
+    /// #stack(
+    ///   align: center,
+    ///   spacing: 2pt,
+    ///   rect(width: 40pt),
+    ///   rect(width: 80pt),
+    ///   align(right, rect(width: 20pt)),
+    /// )
+    /// ```
+    ///
+    /// A horizontal stack can also align its items on their first baseline
+    /// with `{"baseline"}`. This keeps text in items of different sizes on
+    /// one line, for example for captions next to icons. Items without any
+    /// baseline, like shapes, are aligned at the top.
+    ///
+    /// ```example
+    /// #stack(
+    ///   dir: ltr,
+    ///   align: "baseline",
+    ///   spacing: 4pt,
+    ///   text(20pt)[Big],
+    ///   [small],
+    ///   box(inset: 4pt, stroke: gray)[boxed],
+    /// )
+    /// ```
+    pub align: Smart<StackAlignment>,
+
+    /// Whether to stretch the items to the stack's extent along its cross
+    /// axis.
+    ///
+    /// When enabled, the items are laid out into the cross extent of the
+    /// largest item, or into the full available space if the stack itself
+    /// expands along its cross axis. Items with a relative size along the
+    /// cross axis, like `{100%}`, then fill exactly that extent. In a
+    /// horizontal stack, such items don't contribute to the extent, so they
+    /// can't make the stack taller than its other items. This is useful for
+    /// full-height dividers and accent bars.
+    ///
+    /// ```example
+    /// #stack(
+    ///   dir: ltr,
+    ///   spacing: 6pt,
+    ///   stretch: true,
+    ///   rect(width: 3pt, height: 100%, fill: aqua),
+    ///   [First \ Second \ Third],
+    ///   line(angle: 90deg, length: 100%),
+    ///   [Aside],
+    /// )
+    /// ```
+    #[default(false)]
+    pub stretch: bool,
+
+    /// Children given as an array, for example when they were built in a
+    /// loop. These are stacked after the positional children.
+    ///
+    /// ```example
+    /// #let items = range(3).map(i => rect(width: 12pt * (i + 1)))
+    /// #stack(spacing: 2pt, rect(), items: items)
+    /// ```
+    #[external]
+    pub items: Vec<StackChild>,
+
     /// The children to stack along the axis.
     #[variadic]
+    #[parse(
+        let items: Option<Vec<StackChild>> = args.named("items")?;
+        let mut children: Vec<StackChild> = args.all()?;
+        children.extend(items.unwrap_or_default());
+        children
+    )]
     pub children: Vec<StackChild>,
 }
 
@@ -59,8 +246,63 @@ impl LayoutMultiple for Packed<StackElem> {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
-        let mut layouter = StackLayouter::new(self.dir(styles), regions, styles);
-        let axis = layouter.dir.axis();
+        let dir = self.dir(styles);
+        let axis = dir.axis();
+
+        // The stack's alignment must have a component along the cross axis.
+        let mut baseline = false;
+        let cross = match self.align(styles) {
+            Smart::Custom(StackAlignment::Baseline) => {
+                if axis == Axis::Y {
+                    bail!(
+                        self.span(),
+                        "baseline alignment is only supported for horizontal stacks"
+                    );
+                }
+                baseline = true;
+                None
+            }
+            Smart::Custom(StackAlignment::Align(align)) => {
+                let missing = match axis {
+                    Axis::X => align.y().is_none(),
+                    Axis::Y => align.x().is_none(),
+                };
+                if missing {
+                    let (expected, kind) = match axis {
+                        Axis::X => ("vertical", "horizontal"),
+                        Axis::Y => ("horizontal", "vertical"),
+                    };
+                    bail!(
+                        self.span(),
+                        "expected {expected} alignment for {kind} stack";
+                        hint: "the alignment applies along the stack's cross axis"
+                    );
+                }
+                Some(align.resolve(styles).get(axis.other()))
+            }
+            Smart::Auto => None,
+        };
+
+        // Lay the items out into the extent of the largest one along the
+        // cross axis if they should be stretched.
+        let mut regions = regions;
+        if self.stretch(styles) {
+            let other = axis.other();
+            if !regions.expand.get(other) {
+                let extent =
+                    stretch_extent(engine, self.children(), axis, styles, regions)?;
+                regions.size.set(other, extent);
+            }
+            regions.expand.set(other, true);
+            if axis == Axis::X {
+                regions = Regions::one(regions.size, regions.expand);
+            }
+        }
+
+        let justify = self.justify(styles);
+        let collapse = self.collapse(styles);
+        let mut layouter =
+            StackLayouter::new(dir, justify, collapse, cross, baseline, regions, styles);
 
         // Spacing to insert before the next block.
         let spacing = self.spacing(styles);
@@ -87,12 +329,11 @@ impl LayoutMultiple for Packed<StackElem> {
                         continue;
                     }
 
-                    if let Some(kind) = deferred {
-                        layouter.layout_spacing(kind);
+                    // Children without any size don't get spacing around
+                    // them, so that they don't lead to doubled gaps.
+                    if layouter.layout_block(engine, block, styles, deferred)? {
+                        deferred = spacing;
                     }
-
-                    layouter.layout_block(engine, block, styles)?;
-                    deferred = spacing;
                 }
             }
         }
@@ -129,12 +370,89 @@ cast! {
     v: Content => Self::Block(v),
 }
 
+/// How a stack aligns its items along its cross axis.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum StackAlignment {
+    /// Align the items like the `align` function would.
+    Align(Alignment),
+    /// Align the items on their first baseline.
+    Baseline,
+}
+
+cast! {
+    StackAlignment,
+    self => match self {
+        Self::Align(align) => align.into_value(),
+        Self::Baseline => "baseline".into_value(),
+    },
+    "baseline" => Self::Baseline,
+    align: Alignment => Self::Align(align),
+}
+
+/// How a stack combines consecutive spacing children.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum StackCollapse {
+    /// Insert all spacings, so that they add up.
+    #[default]
+    Sum,
+    /// Insert only the largest of the spacings.
+    Max,
+}
+
+/// How a stack distributes leftover space between its items.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum StackJustify {
+    /// Pack the items at the start of the stack.
+    #[default]
+    Start,
+    /// Place the first and last item at the ends of the stack and distribute
+    /// the leftover space evenly between the items.
+    SpaceBetween,
+    /// Give each item equal space on both of its sides, so that the space at
+    /// the ends is half as large as the space between items.
+    SpaceAround,
+    /// Make the space before, between and after the items equally large.
+    SpaceEvenly,
+}
+
+impl StackJustify {
+    /// Computes the space before the first item and between two consecutive
+    /// items, given the leftover space and the number of items.
+    fn gaps(self, remaining: Abs, count: usize) -> (Abs, Abs) {
+        let n = count as f64;
+        match self {
+            Self::Start => (Abs::zero(), Abs::zero()),
+            Self::SpaceBetween if count > 1 => (Abs::zero(), remaining / (n - 1.0)),
+            Self::SpaceBetween => (Abs::zero(), Abs::zero()),
+            Self::SpaceAround => {
+                let gap = remaining / n;
+                (gap / 2.0, gap)
+            }
+            Self::SpaceEvenly => {
+                let gap = remaining / (n + 1.0);
+                (gap, gap)
+            }
+        }
+    }
+}
+
 /// Performs stack layout.
 struct StackLayouter<'a> {
     /// The stacking direction.
     dir: Dir,
+    /// How to distribute leftover space between the items.
+    justify: StackJustify,
+    /// How to combine consecutive spacings.
+    collapse: StackCollapse,
+    /// Spacing that is yet to be inserted because a following spacing may
+    /// still replace it.
+    pending: Option<Spacing>,
     /// The axis of the stacking direction.
     axis: Axis,
+    /// The default alignment of the items along the cross axis.
+    cross: Option<FixedAlignment>,
+    /// Whether to align items on their first baseline.
+    baseline: bool,
     /// The regions to layout children into.
     regions: Regions<'a>,
     /// The inherited styles.
@@ -160,13 +478,22 @@ enum StackItem {
     Absolute(Abs),
     /// Fractional spacing between other items.
     Fractional(Fr),
-    /// A frame for a layouted block.
-    Frame(Frame, Axes<FixedAlignment>),
+    /// A frame for a layouted block, how to align it, and whether to align
+    /// it on its first baseline instead of along the cross axis.
+    Frame(Frame, Axes<FixedAlignment>, bool),
 }
 
 impl<'a> StackLayouter<'a> {
     /// Create a new stack layouter.
-    fn new(dir: Dir, mut regions: Regions<'a>, styles: StyleChain<'a>) -> Self {
+    fn new(
+        dir: Dir,
+        justify: StackJustify,
+        collapse: StackCollapse,
+        cross: Option<FixedAlignment>,
+        baseline: bool,
+        mut regions: Regions<'a>,
+        styles: StyleChain<'a>,
+    ) -> Self {
         let axis = dir.axis();
         let expand = regions.expand;
 
@@ -175,7 +502,12 @@ impl<'a> StackLayouter<'a> {
 
         Self {
             dir,
+            justify,
+            collapse,
+            pending: None,
             axis,
+            cross,
+            baseline,
             regions,
             styles,
             expand,
@@ -189,6 +521,43 @@ impl<'a> StackLayouter<'a> {
 
     /// Add spacing along the spacing direction.
     fn layout_spacing(&mut self, spacing: Spacing) {
+        if self.collapse == StackCollapse::Max {
+            let merged = match self.pending.take() {
+                Some(prev) if self.larger(spacing, prev) => spacing,
+                Some(prev) => prev,
+                None => spacing,
+            };
+            self.pending = Some(merged);
+            return;
+        }
+
+        self.push_spacing(spacing);
+    }
+
+    /// Whether the first spacing takes up more space than the second one.
+    fn larger(&self, a: Spacing, b: Spacing) -> bool {
+        match (a, b) {
+            (Spacing::Fr(a), Spacing::Fr(b)) => a > b,
+            (Spacing::Fr(_), Spacing::Rel(_)) => true,
+            (Spacing::Rel(_), Spacing::Fr(_)) => false,
+            (Spacing::Rel(a), Spacing::Rel(b)) => {
+                let base = self.regions.base().get(self.axis);
+                let resolve = |v: Rel<Length>| v.resolve(self.styles).relative_to(base);
+                resolve(a) > resolve(b)
+            }
+        }
+    }
+
+    /// Insert the spacing that was held back to be combined with following
+    /// spacings.
+    fn flush_spacing(&mut self) {
+        if let Some(spacing) = self.pending.take() {
+            self.push_spacing(spacing);
+        }
+    }
+
+    /// Insert spacing along the spacing direction.
+    fn push_spacing(&mut self, spacing: Spacing) {
         match spacing {
             Spacing::Rel(v) => {
                 // Resolve the spacing and limit it to the remaining space.
@@ -210,28 +579,61 @@ impl<'a> StackLayouter<'a> {
         }
     }
 
-    /// Layout an arbitrary block.
+    /// Layout an arbitrary block, preceded by the given spacing unless the
+    /// block lays out to nothing but zero-sized frames. Returns whether the
+    /// block has any size.
     fn layout_block(
         &mut self,
         engine: &mut Engine,
         block: &Content,
         styles: StyleChain,
-    ) -> SourceResult<()> {
+        spacing: Option<Spacing>,
+    ) -> SourceResult<bool> {
+        // Remember the state in front of the spacing, so that the spacing can
+        // be dropped again if the block turns out to be empty.
+        let items = self.items.len();
+        let finished = self.finished.len();
+        let (used, fr, size, pending) =
+            (self.used, self.fr, self.regions.size, self.pending);
+
+        if let Some(kind) = spacing {
+            self.layout_spacing(kind);
+        }
+
+        self.flush_spacing();
         if self.regions.is_full() {
             self.finish_region();
         }
 
         // Block-axis alignment of the `AlignElement` is respected by stacks.
+        // Other items use the stack's cross-axis alignment, if any.
+        let explicit = block.is::<AlignElem>();
         let align = if let Some(align) = block.to_packed::<AlignElem>() {
-            align.alignment(styles)
-        } else if let Some(styled) = block.to_packed::<StyledElem>() {
-            AlignElem::alignment_in(styles.chain(&styled.styles))
+            align.alignment(styles).resolve(styles)
         } else {
-            AlignElem::alignment_in(styles)
-        }
-        .resolve(styles);
+            let mut align = if let Some(styled) = block.to_packed::<StyledElem>() {
+                AlignElem::alignment_in(styles.chain(&styled.styles))
+            } else {
+                AlignElem::alignment_in(styles)
+            }
+            .resolve(styles);
+            if let Some(cross) = self.cross {
+                align.set(self.axis.other(), cross);
+            }
+            align
+        };
 
         let fragment = block.layout(engine, styles, self.regions)?;
+        let empty = fragment.iter().all(|frame| frame.size().is_zero());
+        if empty && spacing.is_some() && self.finished.len() == finished {
+            self.items.truncate(items);
+            self.used = used;
+            self.fr = fr;
+            self.regions.size = size;
+            self.pending = pending;
+            self.flush_spacing();
+        }
+
         let len = fragment.len();
         for (i, frame) in fragment.into_iter().enumerate() {
             // Grow our size, shrink the region and save the frame for later.
@@ -248,18 +650,36 @@ impl<'a> StackLayouter<'a> {
             self.used.main += gen.main;
             self.used.cross.set_max(gen.cross);
 
-            self.items.push(StackItem::Frame(frame, align));
+            let on_baseline = self.baseline && !explicit;
+            self.items.push(StackItem::Frame(frame, align, on_baseline));
 
             if i + 1 < len {
                 self.finish_region();
             }
         }
 
-        Ok(())
+        Ok(!empty)
     }
 
     /// Advance to the next region.
     fn finish_region(&mut self) {
+        // Determine how far the items aligned on their baseline need to be
+        // shifted and account for that in the stack's cross size.
+        let ascent = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                StackItem::Frame(frame, _, true) => first_baseline(frame),
+                _ => None,
+            })
+            .fold(Abs::zero(), Abs::max);
+        for item in &self.items {
+            if let StackItem::Frame(frame, _, true) = item {
+                let shift = first_baseline(frame).map_or(Abs::zero(), |b| ascent - b);
+                self.used.cross.set_max(shift + frame.height());
+            }
+        }
+
         // Determine the size of the stack in this region depending on whether
         // the region expands.
         let mut size = self
@@ -275,8 +695,28 @@ impl<'a> StackLayouter<'a> {
             size.set(self.axis, full);
         }
 
+        // Distribute the leftover space between the frames if requested and
+        // if there is no fractional spacing which already consumes it.
+        let count = self
+            .items
+            .iter()
+            .filter(|item| matches!(item, StackItem::Frame(..)))
+            .count();
+        let (before, gap) = if self.justify != StackJustify::Start
+            && self.fr.is_zero()
+            && full.is_finite()
+            && remaining > Abs::zero()
+            && count > 0
+        {
+            self.used.main = full;
+            size.set(self.axis, full);
+            self.justify.gaps(remaining, count)
+        } else {
+            (Abs::zero(), Abs::zero())
+        };
+
         let mut output = Frame::hard(size);
-        let mut cursor = Abs::zero();
+        let mut cursor = before;
         let mut ruler: FixedAlignment = self.dir.start().into();
 
         // Place all frames.
@@ -284,7 +724,7 @@ impl<'a> StackLayouter<'a> {
             match item {
                 StackItem::Absolute(v) => cursor += v,
                 StackItem::Fractional(v) => cursor += v.share(self.fr, remaining),
-                StackItem::Frame(frame, align) => {
+                StackItem::Frame(frame, align, on_baseline) => {
                     if self.dir.is_positive() {
                         ruler = ruler.max(align.get(self.axis));
                     } else {
@@ -303,12 +743,16 @@ impl<'a> StackLayouter<'a> {
 
                     // Align along the cross axis.
                     let other = self.axis.other();
-                    let cross = align
-                        .get(other)
-                        .position(size.get(other) - frame.size().get(other));
+                    let cross = if on_baseline {
+                        first_baseline(&frame).map_or(Abs::zero(), |b| ascent - b)
+                    } else {
+                        align
+                            .get(other)
+                            .position(size.get(other) - frame.size().get(other))
+                    };
 
                     let pos = Gen::new(cross, main).to_point(self.axis);
-                    cursor += child;
+                    cursor += child + gap;
                     output.push_frame(pos, frame);
                 }
             }
@@ -324,11 +768,56 @@ impl<'a> StackLayouter<'a> {
 
     /// Finish layouting and return the resulting frames.
     fn finish(mut self) -> Fragment {
+        self.flush_spacing();
         self.finish_region();
         Fragment::frames(self.finished)
     }
 }
 
+/// Determines the largest extent of a stack's items along its cross axis.
+///
+/// For horizontal stacks, the items are measured without any available
+/// height so that relatively sized items don't take up the full region.
+fn stretch_extent(
+    engine: &mut Engine,
+    children: &[StackChild],
+    axis: Axis,
+    styles: StyleChain,
+    regions: Regions,
+) -> SourceResult<Abs> {
+    let mut pod = regions;
+    pod.expand.set(axis, false);
+    if axis == Axis::X {
+        pod = Regions::one(Size::new(pod.size.x, Abs::zero()), Axes::splat(false));
+    }
+
+    let mut extent = Abs::zero();
+    for child in children {
+        let StackChild::Block(block) = child else { continue };
+        if (axis == Axis::X && block.is::<HElem>())
+            || (axis == Axis::Y && block.is::<VElem>())
+        {
+            continue;
+        }
+
+        let fragment = block.measure(engine, styles, pod)?;
+        for frame in fragment.iter() {
+            extent.set_max(frame.size().get(axis.other()));
+        }
+    }
+
+    Ok(extent)
+}
+
+/// The first baseline of a frame, if it has any.
+fn first_baseline(frame: &Frame) -> Option<Abs> {
+    if frame.has_baseline() {
+        Some(frame.baseline())
+    } else {
+        frame.text_baselines().map(|(first, _)| first)
+    }
+}
+
 /// A container with a main and cross component.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
 struct Gen<T> {
@@ -107,6 +107,21 @@ pub struct RotateElem {
     /// ```example
     /// Hello #rotate(90deg, reflow: true)[World]!
     /// ```
+    ///
+    /// Reflowing is useful for vertical table headers: The rotated content's
+    /// height determines the width of an `{auto}` column and its width
+    /// determines the height of the header row.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 3,
+    ///   align: bottom,
+    ///   ..([Name], [Quantity], [Price]).map(
+    ///     rotate.with(-90deg, reflow: true)
+    ///   ),
+    ///   [Apple], [5], [\$2],
+    /// )
+    /// ```
     #[default(false)]
     pub reflow: bool,
 
@@ -98,7 +98,18 @@ impl LayoutRoot for Packed<DocumentElem> {
                         .to_packed::<PageElem>()?
                         .clear_to()?
                 });
-                let run = page.layout(engine, styles, &mut page_counter, extend_to)?;
+
+                // First page overrides only apply once, not to every run after
+                // a page break.
+                let apply_first = !page.first_used().copied().unwrap_or(false);
+
+                let run = page.layout(
+                    engine,
+                    styles,
+                    &mut page_counter,
+                    extend_to,
+                    apply_first,
+                )?;
                 pages.extend(run);
             } else {
                 bail!(child.span(), "unexpected document child");
@@ -1,12 +1,12 @@
 use std::fmt::{self, Debug, Formatter};
 
-use crate::diag::SourceResult;
+use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Args, Cast, Construct, Content, NativeElement, Packed, Set, Smart, StyleChain,
-    Unlabellable,
+    array, cast, dict, elem, Args, Array, Cast, Construct, Content, Dict, NativeElement,
+    Packed, Set, Smart, StyleChain, Unlabellable, Value,
 };
-use crate::layout::{Em, Fragment, Length, Size};
+use crate::layout::{Em, Exclusion, Fragment, HAlignment, Length, Size, Spacing};
 
 /// Arranges text, spacing and inline-level elements into a paragraph.
 ///
@@ -36,11 +36,52 @@ use crate::layout::{Em, Fragment, Length, Size};
 #[elem(title = "Paragraph", Debug, Construct)]
 pub struct ParElem {
     /// The spacing between lines.
+    ///
+    /// Leading is the extra space between the bottom edge of one line and
+    /// the top edge of the next. It can be given in absolute units or in `em`,
+    /// which is resolved relative to the current font size. Like any other
+    /// property, it can be limited to a part of the document by placing the
+    /// set rule in a content block.
+    ///
+    /// ```example
+    /// #set par(leading: 1.5em)
+    /// This paragraph is double-spaced,
+    /// as drafts often are.
+    ///
+    /// #[
+    ///   #set par(leading: 0.3em)
+    ///   #set text(size: 0.8em)
+    ///   A tight caption that does not
+    ///   take up too much space.
+    /// ]
+    /// ```
     #[resolve]
     #[ghost]
     #[default(Em::new(0.65).into())]
     pub leading: Length,
 
+    /// The spacing between paragraphs.
+    ///
+    /// Paragraph spacing collapses with the [spacing]($block.spacing) of
+    /// adjacent blocks: Between a paragraph and a block, only the larger of
+    /// the two is used. A block's explicit [`above`]($block.above) and
+    /// [`below`]($block.below) spacing take precedence. When set to `{auto}`,
+    /// the default, paragraphs use the block spacing.
+    ///
+    /// ```example
+    /// #set par(spacing: 0.4em)
+    /// These paragraphs are
+    /// spaced closely.
+    ///
+    /// But the headings and other
+    /// blocks keep their space.
+    ///
+    /// = Heading
+    /// Paragraph
+    /// ```
+    #[ghost]
+    pub spacing: Smart<Spacing>,
+
     /// Whether to justify text in its line.
     ///
     /// Hyphenation will be enabled for justified paragraphs if the
@@ -54,6 +95,24 @@ pub struct ParElem {
     #[default(false)]
     pub justify: bool,
 
+    /// How to align the last line of a justified paragraph.
+    ///
+    /// When set to `{auto}`, the last line follows the current
+    /// [alignment]($align.alignment), which is the start of the line by
+    /// default. Otherwise, it can be set to a horizontal alignment or to
+    /// `{"justify"}` to justify the last line like all the others. This
+    /// property has no effect if the paragraph isn't justified.
+    ///
+    /// ```example
+    /// #set page(width: 200pt)
+    /// #set par(justify: true, last-line: center)
+    /// This is the first line of a poem,
+    /// and here comes the second one --
+    /// centered at the very end.
+    /// ```
+    #[ghost]
+    pub last_line: Smart<LastLine>,
+
     /// How to determine line breaks.
     ///
     /// When this property is set to `{auto}`, its default value, optimized line
@@ -85,17 +144,65 @@ pub struct ParElem {
     ///
     /// By typographic convention, paragraph breaks are indicated either by some
     /// space between paragraphs or by indented first lines. Consider reducing
-    /// the [paragraph spacing]($block.spacing) to the [`leading`]($par.leading)
-    /// when using this property (e.g. using
-    /// `[#show par: set block(spacing: 0.65em)]`).
+    /// the [paragraph spacing]($par.spacing) to the [`leading`]($par.leading)
+    /// when using this property (e.g. using `[#set par(spacing: 0.65em)]`).
+    ///
+    /// To also indent the first paragraph after a heading or another block,
+    /// pass a dictionary with the indent's `amount` and `{all: true}`.
+    ///
+    /// ```example
+    /// #set par(first-line-indent: (amount: 1em, all: true))
+    /// = Chapter
+    /// #lorem(10)
+    ///
+    /// #lorem(10)
+    /// ```
     #[ghost]
-    pub first_line_indent: Length,
+    pub first_line_indent: FirstLineIndent,
 
     /// The indent all but the first line of a paragraph should have.
+    ///
+    /// A hanging indent keeps the first line flush with the start of the
+    /// paragraph and indents all following lines, as is common for
+    /// bibliographies and definition lists. Justified lines are stretched to
+    /// the indented width and the paragraph's alignment applies within it.
+    ///
+    /// A negative value indents the first line instead. Unlike the
+    /// [`first-line-indent`]($par.first-line-indent), this applies to every
+    /// paragraph, not just consecutive ones.
+    ///
+    /// ```example
+    /// #set par(hanging-indent: 1em)
+    /// #lorem(15)
+    ///
+    /// #set par(hanging-indent: -1em)
+    /// #lorem(15)
+    /// ```
     #[ghost]
     #[resolve]
     pub hanging_indent: Length,
 
+    /// The tab stops that [`tab`]($tab) markers advance to.
+    ///
+    /// Each stop is either a length, which is the stop's distance from the
+    /// start of the line, or an array of such a length and an alignment. The
+    /// alignment determines which part of the content following a marker is
+    /// placed at the stop:
+    ///
+    /// - `start` or `left`: The start of the content. This is the default.
+    /// - `center`: The center of the content.
+    /// - `end` or `right`: The end of the content.
+    /// - `{"decimal"}`: The first decimal point in the content. If there is
+    ///   none, the content ends at the stop.
+    ///
+    /// ```example
+    /// #set par(tabs: (1.5cm, (3cm, center), (5cm, end)))
+    /// A #tab B #tab C #tab D \
+    /// Longer #tab Text #tab Here #tab End
+    /// ```
+    #[ghost]
+    pub tabs: Vec<TabStop>,
+
     /// The contents of the paragraph.
     #[external]
     #[required]
@@ -132,6 +239,7 @@ impl Packed<ParElem> {
         consecutive: bool,
         region: Size,
         expand: bool,
+        exclusion: Option<Exclusion>,
     ) -> SourceResult<Fragment> {
         crate::layout::layout_inline(
             self.children(),
@@ -140,6 +248,7 @@ impl Packed<ParElem> {
             consecutive,
             region,
             expand,
+            exclusion,
         )
     }
 }
@@ -163,6 +272,104 @@ pub enum Linebreaks {
     Optimized,
 }
 
+/// How to align the last line of a justified paragraph.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LastLine {
+    /// Align the last line horizontally.
+    Align(HAlignment),
+    /// Justify the last line like the others.
+    Justify,
+}
+
+cast! {
+    LastLine,
+    self => match self {
+        Self::Align(align) => align.into_value(),
+        Self::Justify => "justify".into_value(),
+    },
+    "justify" => Self::Justify,
+    align: HAlignment => Self::Align(align),
+}
+
+/// Configuration for the indent of a paragraph's first line.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub struct FirstLineIndent {
+    /// The indent of the first line.
+    pub amount: Length,
+    /// Whether to indent every paragraph, not just consecutive ones.
+    pub all: bool,
+}
+
+cast! {
+    FirstLineIndent,
+    self => if self.all {
+        dict! { "amount" => self.amount, "all" => true }.into_value()
+    } else {
+        self.amount.into_value()
+    },
+    amount: Length => Self { amount, all: false },
+    mut dict: Dict => {
+        let amount = dict.take("amount")?.cast()?;
+        let all = dict.take("all").ok().map(Value::cast).transpose()?.unwrap_or(false);
+        dict.finish(&["amount", "all"])?;
+        Self { amount, all }
+    },
+}
+
+/// A tab stop in a paragraph.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct TabStop {
+    /// The distance of the stop from the start of the line.
+    pub pos: Length,
+    /// How to align the content following a tab marker at the stop.
+    pub align: TabAlignment,
+}
+
+cast! {
+    TabStop,
+    self => match self.align {
+        TabAlignment::Start => self.pos.into_value(),
+        align => array![self.pos, align].into_value(),
+    },
+    pos: Length => Self { pos, align: TabAlignment::Start },
+    array: Array => {
+        let mut iter = array.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), Some(b), None) => Self { pos: a.cast()?, align: b.cast()? },
+            _ => bail!("tab stop array must contain exactly two entries"),
+        }
+    },
+}
+
+/// How content is aligned at a tab stop.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TabAlignment {
+    /// The content starts at the stop.
+    Start,
+    /// The content is centered at the stop.
+    Center,
+    /// The content ends at the stop.
+    End,
+    /// The content's first decimal point is at the stop.
+    Decimal,
+}
+
+cast! {
+    TabAlignment,
+    self => match self {
+        Self::Start => HAlignment::Start.into_value(),
+        Self::Center => HAlignment::Center.into_value(),
+        Self::End => HAlignment::End.into_value(),
+        Self::Decimal => "decimal".into_value(),
+    },
+    "decimal" => Self::Decimal,
+    align: HAlignment => match align {
+        HAlignment::Start | HAlignment::Left => Self::Start,
+        HAlignment::Center => Self::Center,
+        HAlignment::End | HAlignment::Right => Self::End,
+    },
+}
+
 /// A paragraph break.
 ///
 /// This starts a new paragraph. Especially useful when used within code like
@@ -178,6 +385,15 @@ pub enum Linebreaks {
 /// }
 /// ```
 ///
+/// Like any other content, a paragraph break can be stored in a variable or
+/// joined with other content, for example in templates that assemble a
+/// document programmatically:
+///
+/// ```example
+/// #let entry(title, body) = strong(title) + parbreak() + body
+/// #entry[Summary][A short overview.]
+/// ```
+///
 /// # Syntax
 /// Instead of calling this function, you can insert a blank line into your
 /// markup to create a paragraph break.
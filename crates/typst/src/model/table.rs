@@ -1,18 +1,22 @@
+use std::borrow::Cow;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use ecow::{eco_format, EcoString};
+use smallvec::smallvec;
 
 use crate::diag::{bail, SourceResult, StrResult, Trace, Tracepoint};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Content, Fold, Packed, Show, Smart, StyleChain,
+    cast, elem, scope, Content, Fold, Packed, Resolve, Show, Smart, StyleChain,
 };
 use crate::layout::{
-    show_grid_cell, Abs, Alignment, Axes, Cell, CellGrid, Celled, Dir, Fragment,
-    GridCell, GridFooter, GridHLine, GridHeader, GridLayouter, GridVLine, LayoutMultiple,
-    Length, LinePosition, OuterHAlignment, OuterVAlignment, Regions, Rel, ResolvableCell,
-    ResolvableGridChild, ResolvableGridItem, Sides, TrackSizings,
+    auto_cells_width, expand_rows_data, handle_overflow, row_width, show_grid_cell, Abs,
+    Alignment, Angle, Axes, Cell, CellGrid, CellOverflow, Celled, Corners, Dir, Fragment,
+    GridBreak, GridCell, GridFooter, GridHLine, GridHeader, GridLayouter, GridOverflow,
+    GridVLine, Gutter, LayoutMultiple, Length, LinePosition, OuterHAlignment,
+    OuterVAlignment, Ratio, Regions, Rel, ResolvableCell, ResolvableGridChild,
+    ResolvableGridItem, RowOverflow, Sides, Sizing, TrackSizings,
 };
 use crate::model::Figurable;
 use crate::syntax::Span;
@@ -47,7 +51,26 @@ use crate::visualize::{Paint, Stroke};
 /// rules on one of them do not affect the other.
 ///
 /// To give a table a caption and make it [referenceable]($ref), put it into a
-/// [figure].
+/// [figure]. Figures containing tables are numbered automatically ("Table 1",
+/// "Table 2", ...) with a counter that is shared by all of them and separate
+/// from the one for images. The counter is available as
+/// `{counter(figure.where(kind: table))}`, the format is determined by the
+/// figure's [`numbering`]($figure.numbering). To restart the numbering in
+/// each chapter, reset the counter at every top-level heading:
+///
+/// ```example
+/// #show heading.where(level: 1): it => {
+///   counter(figure.where(kind: table)).update(0)
+///   it
+/// }
+///
+/// = Results
+/// #figure(table[1], caption: [First])
+/// #figure(table[2], caption: [Second])
+///
+/// = Discussion
+/// #figure(table[3], caption: [Restarted])
+/// ```
 ///
 /// # Example
 ///
@@ -132,23 +155,107 @@ pub struct TableElem {
     /// The gaps between rows and columns. See the [grid documentation]($grid)
     /// for more information on gutters.
     #[external]
-    pub gutter: TrackSizings,
+    pub gutter: Gutter,
+
+    /// An alias for [`gutter`]($table.gutter). See the
+    /// [grid documentation]($grid.gap) for more details.
+    #[external]
+    pub gap: Gutter,
 
     /// The gaps between columns. Takes precedence over `gutter`. See the
     /// [grid documentation]($grid) for more information on gutters.
     #[borrowed]
     #[parse(
-        let gutter = args.named("gutter")?;
-        args.named("column-gutter")?.or_else(|| gutter.clone())
+        let gap: Option<Gutter> = args.named("gap")?;
+        let gutter: Option<Gutter> = args.named("gutter")?.or(gap);
+        args.named("column-gutter")?.or_else(|| gutter.as_ref().map(|g| g.0.x.clone()))
     )]
     pub column_gutter: TrackSizings,
 
     /// The gaps between rows. Takes precedence over `gutter`. See the
     /// [grid documentation]($grid) for more information on gutters.
-    #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
+    #[parse(args.named("row-gutter")?.or_else(|| gutter.map(|g| g.0.y)))]
     #[borrowed]
     pub row_gutter: TrackSizings,
 
+    /// The gap between the [header]($table.header) and the first body row.
+    /// See the [grid documentation]($grid.header-gutter) for more
+    /// information.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   header-gutter: 4pt,
+    ///   table.header[*Fruit*][*Count*],
+    ///   [Apples], [2],
+    ///   [Pears], [3],
+    /// )
+    /// ```
+    pub header_gutter: Smart<Rel<Length>>,
+
+    /// How cells spanning multiple `{auto}` columns size those columns. See
+    /// the [grid documentation]($grid.distribute-spans) for more information.
+    #[default(false)]
+    pub distribute_spans: bool,
+
+    /// Whether `{auto}` rows without any height are separated from their
+    /// neighbours by a [row gutter]($table.row-gutter). See the
+    /// [grid documentation]($grid.collapse-empty-rows) for more information.
+    #[default(false)]
+    pub collapse_empty_rows: bool,
+
+    /// The table's total width. See the [grid documentation]($grid.width) for
+    /// more information.
+    ///
+    /// ```example
+    /// #table(
+    ///   width: 60%,
+    ///   columns: (auto, 1fr),
+    ///   [Label], [Fills the rest],
+    /// )
+    /// ```
+    pub width: Smart<Rel<Length>>,
+
+    /// The table's total height. See the [grid documentation]($grid.height)
+    /// for more information.
+    pub height: Smart<Rel<Length>>,
+
+    /// The maximum height of an `{auto}` row. See the
+    /// [grid documentation]($grid.max-row-height) for more information.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   max-row-height: 2.5em,
+    ///   [Short], lorem(12),
+    /// )
+    /// ```
+    pub max_row_height: Option<Rel<Length>>,
+
+    /// What to do with cell contents that exceed the
+    /// [`max-row-height`]($table.max-row-height). See the
+    /// [grid documentation]($grid.row-overflow) for more information.
+    #[default(RowOverflow::Clip)]
+    pub row_overflow: RowOverflow,
+
+    /// What to do when the table's tracks are wider than the available space.
+    /// See the [grid documentation]($grid.overflow) for more information.
+    ///
+    /// ```example
+    /// #set page(width: 120pt)
+    /// #table(
+    ///   columns: (60pt,) * 3,
+    ///   overflow: "shrink",
+    ///   [A], [B], [C],
+    /// )
+    /// ```
+    #[default(GridOverflow::Warn)]
+    pub overflow: GridOverflow,
+
+    /// Where the table continues when it doesn't fit into the current region.
+    /// See the [grid documentation]($grid.break-to) for more information.
+    pub break_to: GridBreak,
+
     /// How to fill the cells.
     ///
     /// This can be a color or a function that returns a color. The function
@@ -209,6 +316,24 @@ pub struct TableElem {
     #[default(Celled::Value(Sides::splat(Some(Some(Arc::new(Stroke::default()))))))]
     pub stroke: Celled<Sides<Option<Option<Arc<Stroke>>>>>,
 
+    /// How much to round the table's outer corners.
+    ///
+    /// See the [grid documentation]($grid.radius) for more information.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   radius: 4pt,
+    ///   fill: (_, y) => if y == 0 { luma(230) },
+    ///   [*Key*], [*Value*],
+    ///   [Width], [2cm],
+    ///   [Height], [1cm],
+    /// )
+    /// ```
+    #[resolve]
+    #[fold]
+    pub radius: Corners<Option<Rel<Length>>>,
+
     /// How much to pad the cells' content.
     ///
     /// ```example
@@ -232,6 +357,47 @@ pub struct TableElem {
     #[default(Celled::Value(Sides::splat(Some(Abs::pt(5.0).into()))))]
     pub inset: Celled<Sides<Option<Rel<Length>>>>,
 
+    /// Whether to align cells on their decimal separator.
+    ///
+    /// This can be a single boolean, an array of booleans (corresponding to
+    /// each column) or a function that returns a boolean. The function
+    /// receives the cells' column and row indices, starting from zero.
+    ///
+    /// Within a column, decimal-aligned cells are shifted horizontally such
+    /// that the first decimal point in each of them lines up. Cells without a
+    /// decimal point line up the end of their text at that position instead.
+    /// `{auto}`-sized columns grow as needed to fit the shifted cells. Cells
+    /// spanning multiple columns or rows are not affected.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   decimal: (x, y) => x == 1 and y > 0,
+    ///   [*Product*], [*Price*],
+    ///   [Coffee], [3.5],
+    ///   [Cake], [12.25],
+    ///   [Water], [1],
+    ///   [Catering], [1250.125],
+    /// )
+    /// ```
+    pub decimal: Celled<bool>,
+
+    /// Cells given as an array of rows, each of which is an array of cells.
+    /// See the [grid documentation]($grid.rows-data) for more information.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 3,
+    ///   table.header[*Name*][*Age*][*City*],
+    ///   rows-data: (
+    ///     ([Alice], [31]),
+    ///     ([Bob], [27], [Berlin]),
+    ///   ),
+    /// )
+    /// ```
+    #[borrowed]
+    pub rows_data: Vec<Vec<Packed<TableCell>>>,
+
     /// The contents of the table cells, plus any extra table lines specified
     /// with the [`table.hline`]($table.hline) and
     /// [`table.vline`]($table.vline) elements.
@@ -267,10 +433,20 @@ impl LayoutMultiple for Packed<TableElem> {
     ) -> SourceResult<Fragment> {
         let inset = self.inset(styles);
         let align = self.align(styles);
-        let columns = self.columns(styles);
+        let mut columns = self.columns(styles);
+        let data = self.rows_data(styles);
+        if columns.0.is_empty() {
+            let widest = data.iter().map(|row| row_width(row, styles)).max();
+            columns = TrackSizings(smallvec![Sizing::Auto; widest.unwrap_or(0)]);
+        }
         let rows = self.rows(styles);
         let column_gutter = self.column_gutter(styles);
-        let row_gutter = self.row_gutter(styles);
+        let mut row_gutter = Cow::Borrowed(self.row_gutter(styles));
+        let header_gutter = self.header_gutter(styles);
+        if header_gutter.is_custom() && row_gutter.0.is_empty() {
+            // The header gutter replaces a gutter row, so there must be some.
+            row_gutter = Cow::Owned(TrackSizings(smallvec![Sizing::Rel(Rel::zero())]));
+        }
         let fill = self.fill(styles);
         let stroke = self.stroke(styles);
 
@@ -294,7 +470,15 @@ impl LayoutMultiple for Packed<TableElem> {
                 ResolvableGridChild::Item(item.to_resolvable(styles))
             }
         });
-        let grid = CellGrid::resolve(
+        let c = columns.0.len().max(1);
+        let offset = auto_cells_width(children.clone(), c, styles);
+        let data =
+            expand_rows_data(engine, data, c, offset, "table", styles, self.span());
+        let children = children.chain(
+            data.into_iter()
+                .map(|cell| ResolvableGridChild::Item(ResolvableGridItem::Cell(cell))),
+        );
+        let mut grid = CellGrid::resolve(
             tracks,
             gutter,
             children,
@@ -308,8 +492,36 @@ impl LayoutMultiple for Packed<TableElem> {
         )
         .trace(engine.world, tracepoint, self.span())?;
 
-        let layouter = GridLayouter::new(&grid, regions, styles, self.span());
-        layouter.layout(engine)
+        if let Smart::Custom(gap) = header_gutter {
+            grid.set_header_gutter(gap);
+        }
+
+        let width = self
+            .width(styles)
+            .map(|width| width.resolve(styles).relative_to(regions.base().x));
+        let height = self
+            .height(styles)
+            .map(|height| height.resolve(styles).relative_to(regions.base().y));
+        let max_row_height = self
+            .max_row_height(styles)
+            .map(|height| height.resolve(styles).relative_to(regions.base().y));
+        let layouter = GridLayouter::new(&grid, regions, styles, self.span())
+            .with_size(width, height)
+            .with_decimal(self.decimal(styles))
+            .with_max_row_height(max_row_height)
+            .with_row_overflow(self.row_overflow(styles))
+            .with_break_to(self.break_to(styles))
+            .with_distribute_spans(self.distribute_spans(styles))
+            .with_collapse_empty_rows(self.collapse_empty_rows(styles))
+            .with_radius(self.radius(styles).unwrap_or_default());
+
+        let mut fragment = layouter.layout(engine)?;
+
+        // Deal with a table that is wider than its container.
+        let span = self.span();
+        let overflow = self.overflow(styles);
+        handle_overflow(engine, &mut fragment, overflow, regions, "table", styles, span)?;
+        Ok(fragment)
     }
 }
 
@@ -776,8 +988,10 @@ pub struct TableCell {
     pub y: Smart<usize>,
 
     /// The amount of columns spanned by this cell.
-    #[default(NonZeroUsize::ONE)]
-    pub colspan: NonZeroUsize,
+    /// Functions identically to the `colspan` field in
+    /// [`grid.cell`]($grid.cell), so `{auto}` spans to the end of the row.
+    #[default(Smart::Custom(NonZeroUsize::ONE))]
+    pub colspan: Smart<NonZeroUsize>,
 
     /// The amount of rows spanned by this cell.
     #[default(NonZeroUsize::ONE)]
@@ -801,7 +1015,66 @@ pub struct TableCell {
     /// When equal to `{auto}`, a cell spanning only fixed-size rows is
     /// unbreakable, while a cell spanning at least one `{auto}`-sized row is
     /// breakable.
+    ///
+    /// The rows spanned by an unbreakable cell are kept together: If they
+    /// don't fit into the remaining space, the whole rows, including all of
+    /// their other cells, move to the next page. Thus, marking a single cell
+    /// as unbreakable is enough to keep a row, like a row of totals, in one
+    /// piece.
+    ///
+    /// ```example
+    /// #set page(height: 5em)
+    /// #table(
+    ///   columns: 2,
+    ///   [Apples], [2],
+    ///   [Pears], [3],
+    ///   table.cell(breakable: false)[Total \ (all fruit)], [5],
+    /// )
+    /// ```
     pub breakable: Smart<bool>,
+
+    /// How to deal with content that is wider than the cell.
+    ///
+    /// By default, such content wraps within the cell. With `{"spill"}`,
+    /// the content may instead extend into the following cells of the same
+    /// row as long as they are empty, which is useful for sparse layouts
+    /// like timelines. It stops at the first cell that isn't empty and wraps
+    /// within the space gained up to there. Lines between the cells are
+    /// still drawn. Cells spanning multiple rows never spill.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: (30pt,) * 4,
+    ///   table.cell(overflow: "spill")[Kick-off meeting], [], [],
+    ///   [Launch],
+    /// )
+    /// ```
+    #[default(CellOverflow::Wrap)]
+    pub overflow: CellOverflow,
+
+    /// How much to rotate the cell's content. Functions identically to the
+    /// `rotate` field in [`grid.cell`]($grid.cell).
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 3,
+    ///   align: bottom,
+    ///   ..([Name], [Quantity], [Price]).map(table.cell.with(rotate: -90deg)),
+    ///   [Apple], [5], [\$2],
+    /// )
+    /// ```
+    pub rotate: Angle,
+
+    /// How much to scale the cell's content. Functions identically to the
+    /// `scale` field in [`grid.cell`]($grid.cell).
+    #[default(Ratio::one())]
+    pub scale: Ratio,
+
+    /// Whether the cell is sized to fit its rotated or scaled content.
+    /// Functions identically to the `reflow` field in
+    /// [`grid.cell`]($grid.cell).
+    #[default(true)]
+    pub reflow: bool,
 }
 
 cast! {
@@ -820,6 +1093,7 @@ impl ResolvableCell for Packed<TableCell> {
         mut self,
         x: usize,
         y: usize,
+        colspan: NonZeroUsize,
         fill: &Option<Paint>,
         align: Smart<Alignment>,
         inset: Sides<Option<Rel<Length>>>,
@@ -828,7 +1102,6 @@ impl ResolvableCell for Packed<TableCell> {
         styles: StyleChain,
     ) -> Cell {
         let cell = &mut *self;
-        let colspan = cell.colspan(styles);
         let rowspan = cell.rowspan(styles);
         let breakable = cell.breakable(styles).unwrap_or(breakable);
         let fill = cell.fill(styles).unwrap_or_else(|| fill.clone());
@@ -849,6 +1122,7 @@ impl ResolvableCell for Packed<TableCell> {
         let stroke = cell_stroke.fold(stroke).map(Option::flatten);
         cell.push_x(Smart::Custom(x));
         cell.push_y(Smart::Custom(y));
+        cell.push_colspan(Smart::Custom(colspan));
         cell.push_fill(Smart::Custom(fill.clone()));
         cell.push_align(match align {
             Smart::Custom(align) => {
@@ -876,6 +1150,8 @@ impl ResolvableCell for Packed<TableCell> {
             }),
         );
         cell.push_breakable(Smart::Custom(breakable));
+        let spill = cell.overflow(styles) == CellOverflow::Spill;
+        let empty = cell.body().is_empty();
         Cell {
             body: self.pack(),
             fill,
@@ -884,6 +1160,8 @@ impl ResolvableCell for Packed<TableCell> {
             stroke,
             stroke_overridden,
             breakable,
+            spill,
+            empty,
         }
     }
 
@@ -895,7 +1173,7 @@ impl ResolvableCell for Packed<TableCell> {
         (**self).y(styles)
     }
 
-    fn colspan(&self, styles: StyleChain) -> NonZeroUsize {
+    fn colspan(&self, styles: StyleChain) -> Smart<NonZeroUsize> {
         (**self).colspan(styles)
     }
 
@@ -910,7 +1188,14 @@ impl ResolvableCell for Packed<TableCell> {
 
 impl Show for Packed<TableCell> {
     fn show(&self, _engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        show_grid_cell(self.body().clone(), self.inset(styles), self.align(styles))
+        show_grid_cell(
+            self.body().clone(),
+            self.inset(styles),
+            self.align(styles),
+            self.rotate(styles),
+            self.scale(styles),
+            self.reflow(styles),
+        )
     }
 }
 
@@ -28,7 +28,7 @@ use crate::model::{
     ParbreakElem, TermItem, TermsElem,
 };
 use crate::syntax::Span;
-use crate::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
+use crate::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TabElem, TextElem};
 
 /// Realize into an element that is capable of root-level layout.
 #[typst_macros::time(name = "realize root")]
@@ -172,7 +172,10 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
         }
 
         if content.is::<PagebreakElem>() {
-            bail!(content.span(), "pagebreaks are not allowed inside of containers");
+            // Inside of containers, a pagebreak breaks the container's flow.
+            // Whether that is possible is only known during layout.
+            self.flow.0.push(content, styles);
+            Ok(())
         } else {
             bail!(content.span(), "{} is not allowed here", content.func().name());
         }
@@ -214,6 +217,14 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
                 bail!(span, "page configuration is not allowed inside of containers");
             }
             self.interrupt_page(outer, false)?;
+
+            // A set rule with first page overrides applies them anew.
+            let first = PageElem::elem().field_id("first");
+            if outer.is_none() && first.is_some_and(|id| local.has::<PageElem>(id)) {
+                if let Some(doc) = &mut self.doc {
+                    doc.first_used = false;
+                }
+            }
         } else if local.interruption::<ParElem>().is_some()
             || local.interruption::<AlignElem>().is_some()
         {
@@ -292,6 +303,9 @@ struct DocBuilder<'a> {
     keep_next: bool,
     /// Whether the next page should be cleared to an even or odd number.
     clear_next: Option<Parity>,
+    /// Whether the first page overrides in effect were already applied to an
+    /// earlier run of pages.
+    first_used: bool,
 }
 
 impl<'a> DocBuilder<'a> {
@@ -308,9 +322,19 @@ impl<'a> DocBuilder<'a> {
         }
 
         if let Some(page) = content.to_packed::<PageElem>() {
-            let elem = if let Some(clear_to) = self.clear_next.take() {
+            // The first page overrides set by a set rule only apply to the
+            // first run of pages after it. A page created by the `page`
+            // function brings its own overrides.
+            let first_used =
+                self.first_used && page.first(StyleChain::default()).is_none();
+            let elem = if self.clear_next.is_some() || first_used {
                 let mut page = page.clone();
-                page.push_clear_to(Some(clear_to));
+                if let Some(clear_to) = self.clear_next.take() {
+                    page.push_clear_to(Some(clear_to));
+                }
+                if first_used {
+                    page.push_first_used(true);
+                }
                 arenas.store(page.pack())
             } else {
                 content
@@ -318,6 +342,7 @@ impl<'a> DocBuilder<'a> {
 
             self.pages.push(elem, styles);
             self.keep_next = false;
+            self.first_used = true;
             return true;
         }
 
@@ -336,6 +361,7 @@ impl Default for DocBuilder<'_> {
             pages: BehavedBuilder::new(),
             keep_next: true,
             clear_next: None,
+            first_used: false,
         }
     }
 }
@@ -390,6 +416,12 @@ impl<'a> FlowBuilder<'a> {
 
             let (above, below) = if let Some(block) = content.to_packed::<BlockElem>() {
                 (block.above(styles), block.below(styles))
+            } else if let Some(spacing) = content
+                .is::<ParElem>()
+                .then(|| ParElem::spacing_in(styles).custom())
+                .flatten()
+            {
+                (VElem::block_spacing(spacing), VElem::block_spacing(spacing))
             } else {
                 (BlockElem::above_in(styles), BlockElem::below_in(styles))
             };
@@ -427,6 +459,7 @@ impl<'a> ParBuilder<'a> {
             || content.is::<TextElem>()
             || content.is::<HElem>()
             || content.is::<LinebreakElem>()
+            || content.is::<TabElem>()
             || content.is::<SmartQuoteElem>()
             || content
                 .to_packed::<EquationElem>()
@@ -14,6 +14,7 @@ mod shift;
 mod smallcaps_;
 mod smartquote;
 mod space;
+mod tab;
 
 pub use self::case::*;
 pub use self::deco::*;
@@ -27,6 +28,7 @@ pub use self::shift::*;
 pub use self::smallcaps_::*;
 pub use self::smartquote::*;
 pub use self::space::*;
+pub use self::tab::*;
 
 use std::fmt::{self, Debug, Formatter};
 
@@ -59,6 +61,7 @@ pub(super) fn define(global: &mut Scope) {
     global.category(TEXT);
     global.define_elem::<TextElem>();
     global.define_elem::<LinebreakElem>();
+    global.define_elem::<TabElem>();
     global.define_elem::<SmartQuoteElem>();
     global.define_elem::<SubElem>();
     global.define_elem::<SuperElem>();
@@ -0,0 +1,32 @@
+use crate::foundations::{elem, Packed};
+use crate::realize::{Behave, Behaviour};
+
+/// Advances to the next tab stop of the paragraph.
+///
+/// The stops are configured with the paragraph's [`tabs`]($par.tabs) property.
+/// The content between a tab marker and the next marker (or the end of the
+/// line) is aligned at the first stop that lies past the content before the
+/// marker. If there is no such stop, the marker has no effect. Spaces around a
+/// tab marker are removed.
+///
+/// Tab stops are resolved after the paragraph was broken into lines. They are
+/// thus best used with lines that are ended by explicit [line
+/// breaks]($linebreak). In right-to-left paragraphs, tab markers currently
+/// have no effect.
+///
+/// # Example
+/// ```example
+/// #set par(tabs: (2cm, (4.5cm, "decimal")))
+/// *Fruit* #tab *Amount* #tab *Price* \
+/// Apples #tab 3 kg #tab 2.49 \
+/// Cherries #tab 500 g #tab 12.5 \
+/// Melons #tab 2 #tab 112.75
+/// ```
+#[elem(title = "Tab", Behave)]
+pub struct TabElem {}
+
+impl Behave for Packed<TabElem> {
+    fn behaviour(&self) -> Behaviour {
+        Behaviour::Destructive
+    }
+}
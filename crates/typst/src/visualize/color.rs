@@ -255,7 +255,8 @@ impl Color {
         /// The real arguments (the other arguments are just for the docs, this
         /// function is a bit involved, so we parse the arguments manually).
         args: &mut Args,
-        /// The lightness component.
+        /// The lightness component, either as an integer between 0 and 255
+        /// or as a ratio between `{0%}` and `{100%}`.
         #[external]
         lightness: Component,
         /// The alpha component.
@@ -624,7 +625,8 @@ impl Color {
         /// The saturation component.
         #[external]
         saturation: Component,
-        /// The lightness component.
+        /// The lightness component, either as an integer between 0 and 255
+        /// or as a ratio between `{0%}` and `{100%}`.
         #[external]
         lightness: Component,
         /// The alpha component.
@@ -48,6 +48,11 @@ pub struct PolygonElem {
 
     /// The vertices of the polygon. Each point is specified as an array of two
     /// [relative lengths]($relative).
+    ///
+    /// Relative lengths are resolved against the size of the polygon's
+    /// container. The polygon's size is the bounding box of its vertices,
+    /// measured from its top-left corner, so vertices shouldn't have negative
+    /// coordinates.
     #[variadic]
     pub vertices: Vec<Axes<Rel<Length>>>,
 }
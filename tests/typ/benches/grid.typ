@@ -0,0 +1,13 @@
+// Benchmark auto track sizing with a grid of 50 rows and 20 auto columns.
+// This file is skipped by the test runner and meant to be timed with
+// `typst compile`.
+
+#set page(width: auto, height: auto, margin: 1cm)
+#set text(size: 8pt)
+
+#grid(
+  columns: (auto,) * 20,
+  rows: auto,
+  gutter: 4pt,
+  ..range(50 * 20).map(i => [#calc.rem(i * 7919, 1000)]),
+)
@@ -0,0 +1,21 @@
+// Benchmark applying the same template many times under changing state.
+// This file is skipped by the test runner and meant to be timed with
+// `typst watch`, with and without `--no-cache`.
+
+#set page(width: 12cm, height: auto)
+
+#let card(title, body) = block(
+  width: 100%,
+  inset: 6pt,
+  stroke: 0.5pt,
+  radius: 2pt,
+  breakable: false,
+)[
+  #context counter("card").display() #strong(title)
+  #counter("card").step()
+  #body
+]
+
+#for i in range(200) {
+  card[Card #i][#lorem(30)]
+}
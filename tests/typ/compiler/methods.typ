@@ -268,6 +268,19 @@
 #test(rtl.inv(), ltr)
 #test(ttb.inv(), btt)
 #test(btt.inv(), ttb)
+#test(direction.from(left), ltr)
+#test(direction.from(right), rtl)
+#test(direction.from(top), ttb)
+#test(direction.from(bottom), btt)
+#test(direction.to(left), rtl)
+#test(direction.to(right), ltr)
+#test(direction.to(top), btt)
+#test(direction.to(bottom), ttb)
+#test(direction.from(ttb.end()), btt)
+
+---
+// Error: 17-23 cannot convert this alignment to a side
+#direction.from(center)
 
 ---
 // Test angle methods.
@@ -277,6 +290,10 @@
 #test(2deg.deg(), 2.0)
 #test(2.94deg.deg(), 2.94)
 #test(0rad.deg(), 0.0)
+#test(90deg.normalize(), 90deg)
+#test(calc.round((-90deg).normalize().deg(), digits: 6), 270.0)
+#test(calc.round(720deg.normalize().deg(), digits: 6), 0.0)
+#test(calc.round((-1.5rad).normalize().rad(), digits: 6), calc.round(2 * calc.pi - 1.5, digits: 6))
 
 ---
 // Test date methods.
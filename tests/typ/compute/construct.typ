@@ -117,6 +117,25 @@
 // Ref: true
 #stack(dir: ltr, rect(fill: luma(0)), rect(fill: luma(80%)))
 
+---
+// Test gray colors in fills and strokes.
+// Ref: false
+#table(
+  fill: (_, y) => if calc.odd(y) { luma(240) },
+  stroke: luma(50%),
+  [A], [B], [C],
+)
+#test(luma(50%), luma(50%, 100%))
+#test(rect(fill: luma(50%)).fill, luma(50%))
+
+---
+// Error: 7-11 ratio must be between 0% and 100%
+#luma(120%)
+
+---
+// Error: 7-10 number must be between 0 and 255
+#luma(300)
+
 ---
 // Error for values that are out of range.
 // Error: 11-14 number must be between 0 and 255
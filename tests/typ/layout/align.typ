@@ -50,3 +50,104 @@
 ---
 // Error: 8-30 cannot add a vertical and a 2D alignment
 #align(top + (bottom + right), [A])
+
+---
+// Physical alignments ignore the text direction, logical ones follow it.
+// Ref: false
+#set page(width: 100pt, height: auto, margin: 0pt)
+#set text(dir: rtl)
+#let probe(alignment) = align(alignment, box(
+  width: 10pt,
+  height: 5pt,
+  place(top + left, metadata(repr(alignment))),
+))
+
+#for alignment in (left, right, start, end, center) {
+  probe(alignment)
+}
+
+#context {
+  let xs = (:)
+  for it in query(metadata) {
+    xs.insert(it.value, it.location().position().x)
+  }
+  test(xs.left, 0pt)
+  test(xs.right, 90pt)
+  test(xs.start, 90pt)
+  test(xs.end, 0pt)
+  test(xs.center, 45pt)
+}
+
+---
+// Content alone on a page is centered against the full page, unless it is
+// taller than the page.
+// Ref: false
+#set page(width: 100pt, height: 100pt, margin: 0pt)
+#align(center + horizon, block(width: 20pt, height: 30pt)[#metadata(none)<centered>])
+#pagebreak()
+#align(center + horizon, block(width: 20pt, height: 150pt, breakable: false)[#metadata(none)<tall>])
+#context {
+  test(locate(<centered>).position(), (page: 1, x: 40pt, y: 35pt))
+  test(locate(<tall>).position(), (page: 2, x: 40pt, y: 0pt))
+}
+
+---
+// Test that 2D alignments are order-independent and expose their components.
+// Ref: false
+#test(top + right, right + top)
+#test(horizon + center, center + horizon)
+#test((top + right).x, right)
+#test((top + right).y, top)
+#test((top + right).axis(), none)
+
+#set page(width: 100pt, height: 100pt, margin: 0pt)
+#let probe = block(width: 20pt, height: 10pt)[#metadata(none)<probe>]
+#align(top + right, probe)
+#place(bottom + left, probe)
+#place(horizon + center, probe)
+#context {
+  let pos = query(<probe>).map(it => it.location().position())
+  test((pos.at(0).x, pos.at(0).y), (80pt, 0pt))
+  test((pos.at(1).x, pos.at(1).y), (0pt, 90pt))
+  test((pos.at(2).x, pos.at(2).y), (40pt, 45pt))
+}
+
+---
+// Error: 8-39 cannot add two 2D alignments
+#align((top + left) + (bottom + right), [A])
+
+---
+// Test aligning content at fractional positions.
+// Ref: false
+#set page(width: 100pt, height: 100pt, margin: 0pt)
+#let probe = block(width: 20pt, height: 10pt)[#metadata(none)<fraction>]
+#align(position: 25%, probe)
+#align(position: 0.5, probe)
+#align(position: (x: 100%), probe)
+#context {
+  let xs = query(<fraction>).map(it => it.location().position().x)
+  test(xs, (20pt, 40pt, 80pt))
+}
+
+---
+// Test a vertical fractional position.
+// Ref: false
+#set page(width: 100pt, height: 100pt, margin: 0pt)
+#align(
+  position: (x: 50%, y: 25%),
+  block(width: 20pt, height: 10pt)[#metadata(none)<y>],
+)
+#metadata(none)<after>
+#context {
+  let pos = locate(<y>).position()
+  test((pos.x, pos.y), (40pt, 22.5pt))
+  test(locate(<after>).position().page, 2)
+}
+
+---
+// Error: 18-22 position must be between 0% and 100%
+#align(position: 150%)[A]
+
+---
+// Error: 12-25 unexpected argument: position
+#set align(position: 25%)
@@ -0,0 +1,21 @@
+// Test badges.
+
+---
+// A badge is padded horizontally but doesn't change the line height.
+// Ref: false
+#set text(size: 10pt)
+#context {
+  test(measure[A #badge[B]].height, measure[A B].height)
+  test(measure(badge[B]).width, measure[B].width + 8pt)
+  test(measure(badge(inset: 0pt)[B]).width, measure[B].width)
+}
+
+---
+// Defaults can be overridden with set rules.
+// Ref: false
+#set badge(inset: (x: 2pt))
+#context test(measure(badge[B]).width, measure[B].width + 4pt)
+
+---
+// Error: 14-15 expected color, gradient, pattern, or none, found integer
+#badge(fill: 5)[A]
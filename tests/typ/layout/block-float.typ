@@ -0,0 +1,34 @@
+// Test blocks floating at the side of the flow.
+
+---
+// Text wraps around the float and resumes full width below it.
+// Ref: false
+#set page(width: 200pt, height: auto, margin: 10pt)
+#block(float: left, width: 40pt, height: 30pt, clearance: 5pt)
+#metadata(none)<start> #lorem(60)
+
+#metadata(none)<after>
+#context {
+  test(locate(<start>).position().x, 55pt)
+  test(locate(<after>).position().x, 10pt)
+}
+
+---
+// Text beside a right float keeps its start position.
+// Ref: false
+#set page(width: 200pt, height: auto, margin: 10pt)
+#block(float: right, width: 40pt, height: 30pt)
+#metadata(none)<start> #lorem(20)
+#context test(locate(<start>).position().x, 10pt)
+
+---
+// Other blocks are moved below the float.
+// Ref: false
+#set page(width: 200pt, height: auto, margin: 10pt)
+#block(float: left, width: 40pt, height: 30pt)
+#block(width: 20pt, height: 10pt)[#metadata(none)<below>]
+#context test(locate(<below>).position().y, 40pt)
+
+---
+// Error: 15-18 expected `start`, `left`, `right`, or `end`, found top
+#block(float: top)
@@ -7,3 +7,19 @@ Hello
 There
 
 #block(spacing: 20pt)[Further down]
+
+---
+// Test that lead measures from baseline to baseline.
+// Ref: false
+#set page(height: auto, margin: 10pt)
+#set block(spacing: 0pt)
+#block(lead: 30pt)[#metadata(none)<first>A]
+#block(lead: 30pt)[#metadata(none)<second>B]
+#block(lead: 1pt)[#metadata(none)<third>C]
+
+#context {
+  let cap = measure[A].height
+  test(locate(<first>).position().y, 10pt)
+  test(locate(<second>).position().y, 40pt)
+  test(locate(<third>).position().y, 40pt + cap)
+}
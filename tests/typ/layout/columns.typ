@@ -110,3 +110,61 @@ This is a normal page. Very normal.
 #place[OOF]
 #colbreak()
 In flow.
+
+---
+// Test that a weak colbreak in an empty column doesn't skip it.
+// Ref: false
+#set page(height: 100pt, columns: 2)
+A
+#colbreak()
+#place[OOF]
+#colbreak(weak: true)
+B <b>
+
+#context {
+  let pos = locate(<b>).position()
+  test(pos.page, 1)
+  test(pos.x > page.width / 2 - 10pt, true)
+}
+
+---
+// Test a grid spanning two columns and then a page.
+// Ref: false
+#set page(width: 100pt, height: 60pt, margin: 0pt, columns: 2)
+#set columns(gutter: 0pt)
+#let row(name) = block(height: 20pt)[#metadata(name)<row>]
+#grid(..range(8).map(row))
+
+#context {
+  let pos = query(<row>).map(it => it.location().position())
+  test((pos.at(3).page, pos.at(3).x, pos.at(3).y), (1, 50pt, 0pt))
+  test((pos.at(7).page, pos.at(7).x, pos.at(7).y), (2, 0pt, 20pt))
+}
+
+---
+// Test a grid that skips the remaining columns when breaking.
+// Ref: false
+#set page(width: 100pt, height: 60pt, margin: 0pt, columns: 2)
+#set columns(gutter: 0pt)
+#let row(name) = block(height: 20pt)[#metadata(name)<row>]
+#grid(break-to: "region", ..range(4).map(row))
+
+#context {
+  let pos = query(<row>).map(it => it.location().position())
+  test((pos.at(2).page, pos.at(2).x), (1, 0pt))
+  test((pos.at(3).page, pos.at(3).x, pos.at(3).y), (2, 0pt, 0pt))
+}
+
+---
+// Test a table that skips the remaining columns when breaking.
+// Ref: false
+#set page(width: 100pt, height: 60pt, margin: 0pt, columns: 2)
+#set columns(gutter: 0pt)
+#set table(inset: 0pt, stroke: none)
+#let row(name) = block(height: 20pt)[#metadata(name)<row>]
+#table(break-to: "region", ..range(4).map(row))
+
+#context {
+  let pos = query(<row>).map(it => it.location().position())
+  test((pos.at(3).page, pos.at(3).x, pos.at(3).y), (2, 0pt, 0pt))
+}
@@ -5,3 +5,21 @@
   #box(fill: teal, outset: 2pt)[tempor]
   #words.slice(13).join(" ")
 ]
+
+---
+// An image fill doesn't affect the container's size.
+// Ref: false
+#let tiger = image("/assets/images/tiger.jpg", fit: "cover")
+#context {
+  let size = measure(box(width: 20pt, height: 10pt, outset: 2pt, fill: tiger))
+  test(size, (width: 20pt, height: 10pt))
+  test(measure(block(width: 20pt, fill: tiger)[Hi]).width, 20pt)
+}
+
+---
+// Error: 12-16 only images can be used as a fill
+#box(fill: [Hi])
+
+---
+// Error: 14-19 expected color, gradient, pattern, content, or none, found string
+#block(fill: "red")
@@ -48,3 +48,273 @@ First!
   But, soft! what light through yonder window breaks? It is the east, and Juliet
   is the sun.
 ]
+
+---
+// Test that a block moves to the next page if too few lines fit.
+// Ref: false
+#set page(width: 100pt, height: 60pt, margin: 10pt)
+#v(25pt)
+#block[#metadata(none)<broken> #lorem(20)]
+#pagebreak()
+#v(25pt)
+#block(min-lines-before-break: 2)[#metadata(none)<moved> #lorem(20)]
+
+#context {
+  test(locate(<broken>).page(), 1)
+  test(locate(<moved>).page(), 3)
+}
+
+---
+// Test that the continuation marker is shown on all but the last piece.
+// Ref: false
+#set page(width: 100pt, height: 60pt, margin: 10pt)
+#block(continuation: [#metadata(none)<more>])[#lorem(40) #metadata(none)<end>]
+
+#context {
+  let pages = locate(<end>).page()
+  test(query(<more>).len(), pages - 1)
+  test(query(<more>).map(it => it.location().page()), range(1, pages))
+}
+
+---
+// Error: 32-35 expected integer, found string
+#block(min-lines-before-break: "2")
+
+---
+// A shadow doesn't affect the box's size.
+// Ref: false
+#context {
+  let plain = box(width: 20pt, height: 10pt)
+  test(measure(box(width: 20pt, height: 10pt, shadow: gray)), measure(plain))
+  test(measure(box(width: 20pt, height: 10pt, shadow: (dx: -4pt))), measure(plain))
+}
+
+---
+// Error: 14-25 unexpected key "blur", valid keys are "fill", "dx", and "dy"
+#box(shadow: (blur: 2pt))
+
+---
+// Aligned content is aligned within a fixed-size box.
+// Ref: false
+#let cases = (
+  (top + left, 0pt, 0pt),
+  (top + right, 30pt, 0pt),
+  (bottom + left, 0pt, 20pt),
+  (bottom + right, 30pt, 20pt),
+  (horizon + center, 15pt, 10pt),
+)
+
+#for (alignment, ..) in cases {
+  box(width: 40pt, height: 30pt)[#metadata(none)<origin>#align(
+    alignment,
+    block(width: 10pt, height: 10pt)[#metadata(none)<aligned>],
+  )]
+}
+
+#context {
+  let origins = query(<origin>)
+  let aligned = query(<aligned>)
+  for (i, (_, dx, dy)) in cases.enumerate() {
+    let origin = origins.at(i).location().position()
+    let pos = aligned.at(i).location().position()
+    test((pos.x - origin.x, pos.y - origin.y), (dx, dy))
+  }
+}
+
+---
+// Box corners can be rounded individually, missing corners stay square.
+// Ref: false
+#test(
+  box(radius: (top: 5pt, bottom-right: 2pt)).radius,
+  (top-left: 5pt, top-right: 5pt, bottom-right: 2pt),
+)
+#test(box(radius: (left: 5pt, rest: 5pt)).radius, 5pt)
+
+// Large radii are clamped independently.
+#let shapes = (
+  (top-left: 100pt, bottom-right: 3pt),
+  (left: 200%, top-right: 1pt),
+  (rest: 1000pt),
+)
+#context for radius in shapes {
+  let plain = box(width: 40pt, height: 20pt)
+  let rounded = box(width: 40pt, height: 20pt, fill: aqua, stroke: 2pt, clip: true, radius: radius)
+  test(measure(rounded), measure(plain))
+  rounded
+}
+
+---
+// The above and below spacing of adjacent blocks collapses.
+// Ref: false
+#let marker(name) = block(height: 10pt)[#metadata(name)<marker>]
+#let gap(first, second, between: none) = block({
+  block(below: first, marker("a"))
+  between
+  block(above: second, marker("b"))
+})
+
+#gap(10pt, 4pt)
+#gap(3pt, 12pt)
+#gap(10pt, 12pt, between: v(2pt, weak: true))
+#gap(10pt, 12pt, between: v(2pt))
+
+#context {
+  let ys = query(<marker>).map(it => it.location().position().y)
+  let gaps = ys.chunks(2).map(((a, b)) => b - a - 10pt)
+  test(gaps, (10pt, 12pt, 2pt, 14pt))
+}
+
+---
+// Spacing at the start and end of a container is removed.
+// Ref: false
+#context test(
+  measure(block(block(above: 20pt, below: 20pt, height: 10pt))).height,
+  10pt,
+)
+
+---
+// Test sizing a box to the widest of several contents.
+// Ref: false
+#let labels = ([A], [Longer], [Mid])
+#let sized(body) = context {
+  let width = calc.max(..labels.map(it => measure(it).width))
+  box(width: width, body)
+}
+
+#context {
+  let widest = measure[Longer].width
+  test(measure(sized[A]).width, widest)
+  test(measure(sized[Mid]).width, widest)
+}
+
+---
+// Test that a box with a relative width spans its container's content width.
+// Ref: false
+#set page(width: 120pt, margin: 10pt)
+#block(width: 80pt, inset: 5pt)[
+  #layout(size => test(size.width, 70pt))
+  #box(width: 100%, fill: aqua)[A#h(1fr)#metadata(none)<full-end>]
+  #box(width: 50%)[#metadata(none)<half-start>]#box(width: 50%)[#metadata(none)<half-end>]
+]
+#box(width: 100%)[#metadata(none)<page-start>#h(1fr)#metadata(none)<page-end>]
+
+#context {
+  test(locate(<full-end>).position().x, 85pt)
+  test(locate(<half-end>).position().x, 50pt)
+  test(locate(<page-start>).position().x, 10pt)
+  test(locate(<page-end>).position().x, 110pt)
+}
+
+---
+// Test that ellipsized boxes stay on one line and keep their width.
+// Ref: false
+#context {
+  let long = box(width: 40pt, overflow: "ellipsis")[A rather long title]
+  test(measure(long).width, 40pt)
+  test(measure(long).height, measure[A].height)
+}
+
+---
+// Test that right-to-left content is cut off at its logical end, on the left,
+// such that it still starts at the right edge of the box.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#set text(lang: "he", font: "Noto Serif Hebrew")
+#let long = box(width: 40pt, overflow: "ellipsis")[#metadata(none)<start>בנייה נכונה של משפטים ארוכים]
+#long
+#context {
+  test(measure(long).width, 40pt)
+  test(locate(<start>).position().x, 100pt)
+}
+
+---
+// Error: 28-34 expected "visible" or "ellipsis"
+#box(width: 1pt, overflow: "clip")
+
+---
+// Test clipping a box to a polygon.
+// Ref: false
+#box(
+  width: 30pt,
+  height: 30pt,
+  fill: aqua,
+  clip-path: polygon.regular(size: 30pt, vertices: 6),
+)
+#box(
+  clip-path: ((50%, 0%), (100%, 100%), (0%, 100%)),
+  rect(width: 30pt, height: 30pt, fill: eastern),
+)
+
+---
+// Error: 17-23 only polygons can be used as a clip path
+#box(clip-path: rect())
+
+---
+// Test deriving a box's size from its aspect ratio.
+// Ref: false
+#set page(width: 100pt, height: 200pt, margin: 0pt)
+#context {
+  test(measure(box(width: 40pt, aspect: 2)).height, 20pt)
+  test(measure(box(height: 10pt, aspect: 3)).width, 30pt)
+  test(measure(box(width: 10pt, height: 10pt, aspect: 3)).width, 10pt)
+}
+
+#box(aspect: 2, layout(size => [#metadata(size)<aspect>]))
+#context {
+  let size = query(<aspect>).first().value
+  test(size.width, 100pt)
+  test(size.height, 50pt)
+}
+
+---
+// Error: 2-17 aspect ratio must be positive
+#box(aspect: -1)
+
+---
+// Error: 18-31 cannot expand box with aspect ratio into infinite space
+// Hint: 18-31 try giving the box a width or height
+#context measure(box(aspect: 1))
+
+---
+// Test stroking only selected sides of a box.
+// Ref: false
+#test(box(stroke: (bottom: 1pt)).stroke, (bottom: stroke(1pt)))
+#test(box(stroke: (x: red, top: none)).stroke, (left: stroke(red), top: none, right: stroke(red)))
+#context {
+  let plain = measure(box(inset: 2pt)[A])
+  test(measure(box(stroke: (bottom: 1pt), inset: 2pt)[A]), plain)
+}
+
+---
+// Error: 14-40 unexpected key "middle", valid keys are "left", "top", "right", "bottom", "x", "y", and "rest"
+#box(stroke: (bottom: 1pt, middle: 1pt))
+
+---
+// An auto-width box wraps long text at the available width and hugs it.
+// Ref: false
+#set page(width: 140pt, margin: 20pt)
+#box[#box(width: 60pt) #box(width: 70pt) #box(width: 40pt)]#metadata(none)<after>
+
+#context {
+  // Each word ends up on its own line and the box is as wide as the widest.
+  test(locate(<after>).position().x, 20pt + 70pt)
+  test(measure(box[Short]).width, measure[Short].width)
+}
+
+---
+// Content aligned to `horizon` is vertically centered in a fixed-height box.
+// Ref: false
+#box(width: 60pt, height: 40pt)[#metadata(none)<top>#align(horizon)[
+  #block(width: 10pt, height: 10pt)[#metadata(none)<mid>]
+]]
+#box(width: 60pt, height: 40pt)[#metadata(none)<top>#align(horizon + end)[
+  #block(width: 10pt, height: 10pt)[#metadata(none)<mid>]
+]]
+
+#context {
+  test(measure(box(height: 3cm, align(horizon)[Text])).height, 3cm)
+  let tops = query(<top>).map(it => it.location().position())
+  let mids = query(<mid>).map(it => it.location().position())
+  test((mids.at(0).x - tops.at(0).x, mids.at(0).y - tops.at(0).y), (0pt, 15pt))
+  test((mids.at(1).x - tops.at(1).x, mids.at(1).y - tops.at(1).y), (50pt, 15pt))
+}
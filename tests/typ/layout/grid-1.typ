@@ -39,3 +39,205 @@
   align(center)[A bit more to the top],
   [],
 )
+
+---
+// An integer number of rows creates that many auto rows. More cells add rows
+// and fewer cells leave the remaining rows empty.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#let size(..args) = measure(grid(..args))
+#context {
+  test(size(rows: 3, cell, cell), (width: 10pt, height: 20pt))
+  test(size(columns: 2, rows: 2, ..(cell,) * 3), (width: 20pt, height: 20pt))
+  test(size(columns: 2, rows: 2, ..(cell,) * 6), (width: 20pt, height: 30pt))
+  test(size(columns: 2, rows: (5pt,), ..(cell,) * 6).height, 3 * 5pt)
+}
+
+---
+// Test a grid with a fixed total size.
+// Ref: false
+#context {
+  let size = measure(grid(width: 100pt, columns: (1fr,), [A]))
+  test(size.width, 100pt)
+
+  let size = measure(grid(width: 100pt, columns: (20pt, 1fr, 1fr), [A], [B], [C]))
+  test(size.width, 100pt)
+
+  let size = measure(grid(width: 100pt, columns: (auto,), [A]))
+  test(size.width, 100pt)
+
+  let size = measure(grid(height: 50pt, rows: (10pt, 1fr), [A], [B]))
+  test(size.height, 50pt)
+}
+
+---
+// Test that fractional columns exactly fill a relative width.
+// Ref: false
+#set page(width: 120pt, height: auto, margin: 10pt)
+#grid(
+  width: 50%,
+  columns: (1fr, 1fr),
+  [A], [B#h(1fr)#metadata(none)<end>]
+)
+#context test(locate(<end>).position().x, 60pt)
+
+---
+// Test a table with a fixed total size.
+// Ref: false
+#context {
+  let size = measure(table(width: 100pt, height: 50pt, columns: (1fr,), [A]))
+  test(size, (width: 100pt, height: 50pt))
+}
+
+---
+// Test that fractional columns in a repeated pattern share the remaining
+// width equally.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#grid(
+  columns: (40pt, 1fr) * 2,
+  ..range(4).map(i => [#metadata(i)<col>]),
+)
+
+#context {
+  let xs = query(<col>).map(it => it.location().position().x)
+  test(xs, (0pt, 40pt, 100pt, 140pt))
+}
+
+---
+// Test that nested arrays are expanded row by row and short rows are padded.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#grid(
+  columns: (50pt,) * 3,
+  rows: 10pt,
+  [#metadata("a")<data>],
+  rows-data: (
+    ([#metadata("b")<data>],),
+    ([#metadata("c")<data>], [#metadata("d")<data>]),
+  ),
+)
+
+#context {
+  let positions = query(<data>).map(it => it.location().position())
+  test(positions.map(p => p.x), (0pt, 0pt, 0pt, 50pt))
+  test(positions.map(p => p.y), (0pt, 10pt, 20pt, 20pt))
+}
+
+---
+// Test that the longest row determines the columns if there are none.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#grid(
+  rows-data: (
+    (box(width: 20pt), box(width: 30pt)),
+    (box(width: 10pt), [], [#metadata(none)<third>]),
+  ),
+)
+
+#context test(locate(<third>).position().x, 50pt)
+
+---
+// Test that tables take nested arrays as well and place them after a header.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#set table(inset: 0pt, stroke: none)
+#table(
+  rows: 10pt,
+  table.header([A], [B], [#metadata("header")<row>]),
+  rows-data: (
+    ([#metadata("a")<row>],),
+    (box(width: 20pt), box(width: 30pt), [#metadata("b")<row>]),
+  ),
+)
+
+#context {
+  let positions = query(<row>).map(it => it.location().position())
+  test(positions.map(p => p.x), (50pt, 0pt, 50pt))
+  test(positions.map(p => p.y), (0pt, 10pt, 20pt))
+}
+
+---
+// Ref: false
+// Warning: 2-49 row 1 of `rows-data` has 3 cells, but the grid only has 2 columns
+// Hint: 2-49 the remaining cells continue on the next row
+#grid(columns: 2, rows-data: (([A], [B], [C]),))
+
+---
+// Ref: false
+// Warning: 2-50 row 1 of `rows-data` has 3 cells, but the table only has 2 columns
+// Hint: 2-50 the remaining cells continue on the next row
+#table(columns: 2, rows-data: (([A], [B], [C]),))
+
+---
+// Test that auto-fill tracks take up the remaining width.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#grid(
+  columns: (40pt, "auto-fill", 20pt, "auto-fill"),
+  ..range(4).map(i => [#metadata(i)<fill>]),
+)
+
+#context {
+  let xs = query(<fill>).map(it => it.location().position().x)
+  test(xs, (0pt, 40pt, 110pt, 130pt))
+}
+
+#context test(
+  measure(grid(columns: (10pt, "auto-fill"), [])).width,
+  measure(grid(columns: (10pt, 1fr), [])).width,
+)
+
+---
+// Error: 17-28 expected "auto-fill", auto, relative length, or fraction
+#grid(columns: ("auto-full",))
+
+---
+// A grid that is too wide warns by default.
+// Ref: false
+#set page(width: 120pt, margin: 10pt)
+// Warning: 2-44 grid is wider than the available space
+// Hint: 2-44 set `overflow` to "shrink" or "clip" to make it fit
+#grid(columns: (60pt, 60pt), [A], [B], [C])
+
+---
+// A grid that is too wide can shrink to fit.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#stack(
+  grid(columns: (100pt, 100pt), rows: 20pt, overflow: "shrink"),
+  [#metadata(none) <after>],
+)
+#box(grid(columns: (100pt, 100pt), overflow: "shrink"))#metadata(none)<end>
+#context {
+  test(locate(<after>).position().y, 10pt)
+  test(locate(<end>).position().x, 100pt)
+}
+
+---
+// A grid that is too wide can be clipped.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#box(grid(columns: (60pt, 60pt), overflow: "clip", [A], [B]))#metadata(none)<end>
+#context test(locate(<end>).position().x, 100pt)
+
+---
+// A grid that is too wide can be an error.
+#set page(width: 120pt, margin: 10pt)
+// Error: 2-58 grid is wider than the available space
+// Hint: 2-58 try reducing the width of its columns
+#grid(columns: (60pt, 60pt), overflow: "error", [A], [B])
+
+---
+// Tables deal with being too wide in the same way.
+// Ref: false
+#set page(width: 120pt, margin: 10pt)
+// Warning: 2-45 table is wider than the available space
+// Hint: 2-45 set `overflow` to "shrink" or "clip" to make it fit
+#table(columns: (60pt, 60pt), [A], [B], [C])
+#box(table(columns: (60pt, 60pt), overflow: "clip", [A], [B]))#metadata(none)<end>
+#context test(locate(<end>).position().x, 110pt)
+
+---
+// Error: 17-25 expected "warn", "shrink", "clip", or "error"
+#grid(overflow: "scroll")
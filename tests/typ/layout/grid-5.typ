@@ -38,3 +38,63 @@
   stroke: aqua,
   [a]
 )
+
+---
+// Test passing children as an array.
+// Ref: false
+#let cells = range(5).map(i => box(width: 10pt, height: i * 1pt))
+#context {
+  let expected = measure(grid(columns: 2, ..cells))
+  test(measure(grid(columns: 2, items: cells)), expected)
+  test(measure(grid(columns: 2, cells.first(), items: cells.slice(1))), expected)
+}
+
+---
+// Test capping the height of auto rows.
+// Ref: false
+#let tall = box(width: 10pt, height: 50pt)
+#context {
+  test(measure(grid(max-row-height: 20pt, tall)).height, 20pt)
+  test(measure(grid(max-row-height: 20pt, box(height: 5pt))).height, 5pt)
+  test(measure(grid(max-row-height: 20pt, row-overflow: "visible", tall)).height, 20pt)
+  test(measure(grid(rows: (auto, 30pt), max-row-height: 5pt, tall, tall)).height, 35pt)
+}
+
+---
+// Tables cap their auto rows in the same way.
+// Ref: false
+#set table(inset: 0pt, stroke: none)
+#let tall = box(width: 10pt, height: 50pt)
+#context {
+  test(measure(table(max-row-height: 20pt, tall)).height, 20pt)
+  test(measure(table(max-row-height: 20pt, row-overflow: "visible", tall)).height, 20pt)
+}
+
+---
+// A capped row can continue in the next region instead.
+// Ref: false
+#set page(height: 100pt, margin: 10pt)
+#grid(
+  max-row-height: 30pt,
+  row-overflow: "break",
+  block(width: 10pt, height: 50pt),
+  [#block(width: 10pt, height: 5pt)<after>],
+)
+#context {
+  test(locate(<after>).page(), 2)
+  test(locate(<after>).position().y, 30pt)
+}
+
+---
+// Rows that can't break are clipped instead.
+// Ref: false
+#let capped = grid(
+  max-row-height: 20pt,
+  row-overflow: "break",
+  box(width: 10pt, height: 50pt),
+)
+#context test(measure(capped).height, 20pt)
+
+---
+// Error: 21-29 expected "clip", "visible", or "break"
+#grid(row-overflow: "scroll")
@@ -131,3 +131,78 @@
 ---
 // Error: 7-19 cannot use `table.cell` as a grid cell; use `grid.cell` instead
 #grid(table.cell[])
+
+---
+// Test function children receiving their position.
+// Ref: false
+#let pos = (x, y) => metadata((x, y))
+#grid(
+  columns: 3,
+  gutter: 5pt,
+  grid.cell(colspan: 2, pos),
+  pos, grid.cell(x: 1, y: 1, pos), pos, pos,
+)
+#context test(
+  query(metadata).map(it => it.value),
+  ((0, 0), (2, 0), (0, 1), (1, 1), (2, 1)),
+)
+
+---
+// Error: 7-8 expected content or function, found integer
+#grid(1)
+
+---
+// Test that a row with an unbreakable cell moves to the next page as a whole.
+// Ref: false
+#set page(height: 50pt, margin: 10pt)
+#grid(
+  columns: 2,
+  block(height: 20pt), [],
+  grid.cell(breakable: false)[Total \ (all)], [#metadata(none)<total>],
+)
+
+#context {
+  let total = locate(<total>).position()
+  test((total.page, total.y), (2, 10pt))
+}
+
+---
+// Test that cell content spills into the following empty cells.
+// Ref: false
+#context {
+  let line = measure[A].height
+  let spill = grid.cell(overflow: "spill")[A long label]
+  test(measure(grid(columns: (30pt,) * 3, spill, [], [])).height, line)
+  test(measure(grid(columns: (30pt,) * 3, spill, [], [B])).height, line)
+  test(measure(grid(columns: (30pt,) * 3, spill, [B], [])).height > line, true)
+}
+
+---
+// Transformed cells are sized to the transformed content by default.
+// Ref: false
+#let body = box(width: 40pt, height: 10pt)
+#let size(cell) = {
+  let size = measure(grid(cell))
+  (calc.round(size.width.pt()), calc.round(size.height.pt()))
+}
+#context {
+  test(size(grid.cell(rotate: 90deg, body)), (10, 40))
+  test(size(grid.cell(rotate: 90deg, reflow: false, body)), (40, 10))
+  test(size(grid.cell(scale: 50%, body)), (20, 5))
+  test(size(grid.cell(scale: 50%, reflow: false, body)), (40, 10))
+}
+
+---
+// Table cells keep their inset around the transformed content.
+// Ref: false
+#context {
+  let size = measure(table(
+    stroke: none,
+    table.cell(rotate: -90deg, box(width: 40pt, height: 10pt)),
+  ))
+  test((calc.round(size.width.pt()), calc.round(size.height.pt())), (20, 50))
+}
+
+---
+// Error: 25-29 expected angle, found boolean
+#grid(grid.cell(rotate: true)[A])
@@ -139,3 +139,113 @@
   [e], [g], grid.cell(colspan: 2)[eee\ e\ e\ e],
   grid.cell(colspan: 4)[eeee e e e]
 )
+
+---
+// By default, a wider colspan only grows the last auto column it spans.
+// Ref: false
+#set grid(inset: 0pt)
+#context {
+  let cols = query(metadata).map(it => it.location().position().x)
+  test(cols.at(1) - cols.at(0), 10pt)
+  test(cols.at(2) - cols.at(1), 70pt)
+}
+#grid(
+  columns: 3,
+  box(width: 10pt)[#metadata(none)], box(width: 30pt)[#metadata(none)], [#metadata(none)],
+  grid.cell(colspan: 2, box(width: 80pt)),
+)
+
+---
+// With `distribute-spans`, auto columns are sized from single cells first. A
+// wider colspan then grows all spanned auto columns proportionally instead of
+// only the last one.
+// Ref: false
+#set grid(inset: 0pt, distribute-spans: true)
+#context {
+  let cols = query(metadata).map(it => it.location().position().x)
+  test(cols.at(1) - cols.at(0), 20pt)
+  test(cols.at(2) - cols.at(1), 60pt)
+}
+#grid(
+  columns: 3,
+  box(width: 10pt)[#metadata(none)], box(width: 30pt)[#metadata(none)], [#metadata(none)],
+  grid.cell(colspan: 2, box(width: 80pt)),
+)
+
+---
+// Tables distribute spans in the same way.
+// Ref: false
+#set table(inset: 0pt, stroke: none, distribute-spans: true)
+#context {
+  let cols = query(<cell>).map(it => it.location().position().x)
+  test(cols.at(1) - cols.at(0), 20pt)
+  test(cols.at(2) - cols.at(1), 60pt)
+}
+#table(
+  columns: 3,
+  box(width: 10pt)[#metadata(none)<cell>], box(width: 30pt)[#metadata(none)<cell>], [#metadata(none)<cell>],
+  table.cell(colspan: 2, box(width: 80pt)),
+)
+
+---
+// Colspans that already fit don't expand the spanned columns.
+// Ref: false
+#context {
+  let small = measure(grid(columns: 2, box(width: 20pt), box(width: 30pt)))
+  let spanned = measure(grid(
+    columns: 2,
+    box(width: 20pt), box(width: 30pt),
+    grid.cell(colspan: 2, box(width: 40pt)),
+  ))
+  test(small.width, spanned.width)
+}
+
+---
+// Spanned auto columns without any width of their own share the excess
+// equally. Narrower colspans are considered first, so the wider one doesn't
+// need to grow the columns any further here.
+// Ref: false
+#set grid(distribute-spans: true)
+#context {
+  let width(..cells) = measure(grid(columns: 3, ..cells)).width
+  test(width(grid.cell(colspan: 3, box(width: 90pt))), 90pt)
+  test(
+    width(
+      grid.cell(colspan: 3, box(width: 90pt)),
+      grid.cell(colspan: 2, box(width: 80pt)),
+    ),
+    90pt,
+  )
+}
+
+---
+// A cell with `colspan: auto` spans to the end of its row, wherever it starts.
+// Ref: false
+#grid(
+  columns: (10pt, 20pt, 30pt, 40pt),
+  [A], grid.cell(colspan: auto)[#layout(size => [#metadata(size.width)<fill>])],
+  [#metadata(none) <next>],
+)
+
+#context {
+  test(query(<fill>).first().value, 90pt)
+  test(locate(<next>).position().x, locate(<fill>).position().x - 10pt)
+}
+
+---
+// A cell with `colspan: auto` at the start of a row spans the whole row.
+// Ref: false
+#context test(
+  measure(grid(columns: 3, grid.cell(colspan: auto, box(width: 60pt)))).width,
+  60pt,
+)
+
+---
+// A cell spanning to the end of its row still can't span placed cells.
+// Error: 4:8-4:35 cell would span a previously placed cell at column 2, row 0
+// Hint: 4:8-4:35 try specifying your cells in a different order or reducing the cell's rowspan or colspan
+#grid(
+  columns: 3,
+  grid.cell(x: 2, y: 0)[x],
+  [a], grid.cell(colspan: auto)[b]
+)
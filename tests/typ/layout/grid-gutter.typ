@@ -0,0 +1,123 @@
+// Test the different forms of the `gutter` argument.
+
+---
+// A single size and an array apply to both axes.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#context {
+  let size = measure(grid(columns: 2, gutter: 5pt, ..(cell,) * 4))
+  test(size, (width: 25pt, height: 25pt))
+  let size = measure(grid(columns: 3, gutter: (1pt, 2pt), ..(cell,) * 9))
+  test(size, (width: 33pt, height: 33pt))
+}
+
+---
+// A dictionary configures the axes separately.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#context {
+  let size = measure(grid(columns: 2, gutter: (x: 5pt, y: 3pt), ..(cell,) * 4))
+  test(size, (width: 25pt, height: 23pt))
+  let size = measure(table(columns: 2, inset: 0pt, gutter: (y: 4pt), ..(cell,) * 4))
+  test(size, (width: 20pt, height: 24pt))
+}
+
+---
+// The dedicated arguments take precedence over `gutter`.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#context {
+  let size = measure(grid(
+    columns: 2,
+    gutter: (x: 5pt, y: 3pt),
+    column-gutter: 1pt,
+    ..(cell,) * 4,
+  ))
+  test(size, (width: 21pt, height: 23pt))
+}
+
+---
+// Error: 15-23 unexpected key "z", valid keys are "x" and "y"
+#grid(gutter: (z: 1pt))
+
+---
+// Test that empty rows can be kept from doubling the gutter.
+// Ref: false
+#set page(width: 100pt, height: auto, margin: 0pt)
+#let probe(name) = block(height: 10pt)[#metadata(name)<grid-probe>]
+#grid(row-gutter: 5pt, collapse-empty-rows: true, probe("a"), [], probe("b"), [])
+
+#context {
+  let ys = query(<grid-probe>).map(it => it.location().position().y)
+  test(ys, (0pt, 15pt))
+  test(measure(grid(row-gutter: 5pt, probe("a"), [], probe("b"))).height, 30pt)
+  test(measure(grid(
+    row-gutter: 5pt,
+    collapse-empty-rows: true,
+    probe("a"), [], probe("b"),
+  )).height, 25pt)
+  test(measure(table(
+    row-gutter: 5pt,
+    inset: 0pt,
+    stroke: none,
+    collapse-empty-rows: true,
+    probe("a"), [], probe("b"),
+  )).height, 25pt)
+}
+
+---
+// Test that fractional gutters spread fixed columns across the width.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#grid(
+  columns: (20pt,) * 3,
+  gutter: 1fr,
+  ..range(3).map(i => [#metadata(i)<even>]),
+)
+#grid(
+  columns: (20pt,) * 3,
+  column-gutter: (1fr, 3fr),
+  ..range(3).map(i => [#metadata(i)<split>]),
+)
+
+#context {
+  test(query(<even>).map(it => it.location().position().x), (0pt, 90pt, 180pt))
+  test(query(<split>).map(it => it.location().position().x), (0pt, 55pt, 180pt))
+}
+
+---
+// `gap` is an alias for `gutter`.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#context {
+  let size = measure(grid(columns: 2, gap: 5pt, ..(cell,) * 4))
+  test(size, (width: 25pt, height: 25pt))
+  let size = measure(grid(columns: 2, gap: 5pt, gutter: 1pt, ..(cell,) * 4))
+  test(size, (width: 21pt, height: 21pt))
+  let size = measure(table(columns: 2, inset: 0pt, gap: (x: 2pt), ..(cell,) * 4))
+  test(size, (width: 22pt, height: 20pt))
+}
+
+---
+// Spacer rows leave a gap only after the given rows.
+// Ref: false
+#grid(
+  rows: 10pt,
+  spacer-rows: ((1, 1cm),),
+  ..range(4).map(i => [#metadata(i)<row>]),
+)
+
+#context {
+  let ys = query(<row>).map(it => it.location().position().y)
+  let gaps = range(3).map(i => ys.at(i + 1) - ys.at(i))
+  test(gaps, (10pt, 10pt + 1cm, 10pt))
+  let height(..args) = measure(grid(rows: 10pt, ..args, [], [], [], [])).height
+  test(height(spacer-rows: ((1, 1cm),)), 40pt + 1cm)
+  test(height(spacer-rows: ((1, 1cm),), row-gutter: 2pt), 44pt + 1cm)
+  test(height(spacer-rows: ((3, 1cm),)), 40pt)
+  test(height(spacer-rows: ((1000000000, 1cm),)), 40pt)
+}
+
+---
+// Error: 20-27 spacer row array must contain exactly two entries
+#grid(spacer-rows: ((1,),))
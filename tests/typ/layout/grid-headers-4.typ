@@ -110,3 +110,89 @@
     [b]
   )
 )
+
+---
+// Test that a body rowspan breaks below the repeated header.
+// Ref: false
+#set page(height: 60pt, margin: 0pt)
+#grid(
+  columns: 2,
+  rows: 20pt,
+  grid.header([#metadata(none)<head>], []),
+  grid.cell(rowspan: 3)[#metadata(none)<span>],
+  [a], [b], [#metadata(none)<c>],
+)
+
+#context {
+  let heads = query(<head>).map(it => it.location().position())
+  test(heads.map(it => (it.page, it.y)), ((1, 0pt), (2, 0pt)))
+  let span = locate(<span>).position()
+  test((span.page, span.y), (1, 20pt))
+  let c = locate(<c>).position()
+  test((c.page, c.y), (2, 20pt))
+}
+
+---
+// Test that a rowspan in the header makes it include the spanned rows, but
+// only explicitly positioned cells join them.
+// Ref: false
+#set page(height: 60pt, margin: 0pt)
+#grid(
+  columns: 2,
+  rows: 15pt,
+  grid.header(grid.cell(rowspan: 2)[H], [x]),
+  [#metadata(none)<below>], [],
+  grid.cell(x: 1, y: 1)[#metadata(none)<joined>],
+  ..range(6).map(_ => []),
+)
+
+#context {
+  let below = locate(<below>).position()
+  test((below.page, below.y), (1, 30pt))
+  let joined = query(<joined>).map(it => it.location().position())
+  test(joined.map(it => (it.page, it.y)), ((1, 15pt), (2, 15pt)))
+}
+
+---
+// Test a distinct gap between the header and the body.
+// Ref: false
+#let rows(..args) = grid(
+  rows: 10pt,
+  ..args,
+  grid.header[#metadata(none)<row>],
+  [#metadata(none)<row>],
+  [#metadata(none)<row>],
+)
+
+#rows(row-gutter: 2pt, header-gutter: 8pt)
+#rows(header-gutter: 5pt)
+#rows(row-gutter: 2pt, header-gutter: auto)
+
+#context {
+  let ys = query(<row>).map(it => it.location().position().y)
+  let offsets(i) = ys.slice(i, i + 3).map(y => y - ys.at(i))
+  test(offsets(0), (0pt, 18pt, 30pt))
+  test(offsets(3), (0pt, 15pt, 25pt))
+  test(offsets(6), (0pt, 12pt, 24pt))
+}
+
+---
+// Test the header gutter of tables.
+// Ref: false
+#let rows(..args) = table(
+  rows: 10pt,
+  ..args,
+  table.header[#metadata(none)<cell>],
+  [#metadata(none)<cell>],
+  [#metadata(none)<cell>],
+)
+
+#rows(row-gutter: 2pt, header-gutter: 8pt)
+#rows(header-gutter: 5pt)
+
+#context {
+  let ys = query(<cell>).map(it => it.location().position().y)
+  let offsets(i) = ys.slice(i, i + 3).map(y => y - ys.at(i))
+  test(offsets(0), (0pt, 18pt, 30pt))
+  test(offsets(3), (0pt, 15pt, 25pt))
+}
@@ -0,0 +1,29 @@
+// Test sizing of grids nested in other grids.
+
+---
+// An auto column of the outer grid takes on the natural width of an inner
+// grid.
+// Ref: false
+#let inner = grid(
+  columns: (auto, 2em, auto),
+  column-gutter: 4pt,
+  [A], [B], [Wide cell],
+  [C], [D], [E],
+)
+
+#context {
+  let natural = measure(inner).width
+  let outer = grid(columns: (auto, auto), inner, [Next])
+  test(measure(outer).width, natural + measure[Next].width)
+  test(measure(grid(inner)).width, natural)
+}
+
+---
+// Nesting twice propagates through both levels.
+// Ref: false
+#let innermost = grid(columns: 2, [A], [B])
+#let middle = grid(columns: (auto, 1em), innermost, [])
+#context test(
+  measure(grid(columns: (auto,), middle)).width,
+  measure(innermost).width + 1em.to-absolute(),
+)
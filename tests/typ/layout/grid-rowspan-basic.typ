@@ -250,3 +250,37 @@
   table.cell(breakable: false, block(width: 2em, height: 100%, fill: red)),
   table.cell(breakable: false, rowspan: 2, block(width: 2em, height: 100%, fill: red)),
 )
+
+---
+// A short rowspan never shrinks the rows it spans.
+// Ref: false
+#let cell(h) = box(width: 5pt, height: h)
+#context {
+  let short = grid(columns: 2, grid.cell(rowspan: 2, cell(5pt)), cell(20pt), cell(20pt))
+  test(measure(short).height, 40pt)
+}
+
+---
+// A tall rowspan grows the last spanned auto row, but not fixed rows.
+// Ref: false
+#let cell(h) = box(width: 5pt, height: h)
+#context {
+  let tall = grid(columns: 2, grid.cell(rowspan: 2, cell(60pt)), cell(20pt), cell(20pt))
+  test(measure(tall).height, 60pt)
+  let fixed = grid(
+    columns: 2,
+    rows: (10pt, 10pt),
+    grid.cell(rowspan: 2, cell(60pt)),
+    cell(5pt),
+    cell(5pt),
+  )
+  test(measure(fixed).height, 20pt)
+}
+
+---
+// A rowspan past the last row adds empty rows.
+// Ref: false
+#context {
+  let spanning = grid(columns: 2, rows: 10pt, [a], grid.cell(rowspan: 3)[b])
+  test(measure(spanning).height, 30pt)
+}
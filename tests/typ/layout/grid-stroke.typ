@@ -432,3 +432,28 @@
 ---
 // Error: 24-27 expected `start`, `left`, `right`, or `end`, found top
 #table.vline(position: top)
+
+---
+// Rounded corners don't affect the grid's layout, also across pages, with
+// gutter, with thick borders, and in RTL.
+// Ref: false
+#set page(height: 6em)
+#let cells = range(12).map(str)
+#context {
+  for args in (
+    (columns: 3, stroke: 1pt),
+    (columns: 3, gutter: 3pt, stroke: 4pt, fill: aqua),
+    (columns: 2, stroke: (x, y) => if x == 0 { red }, fill: yellow),
+  ) {
+    let plain = measure(grid(..args, ..cells))
+    test(measure(grid(..args, radius: 5pt, ..cells)), plain)
+    test(measure(table(..args, radius: (top-left: 50%, rest: 2pt), ..cells)), measure(table(..args, ..cells)))
+  }
+}
+#table(columns: 3, radius: 100pt, fill: green, ..cells)
+#set text(dir: rtl)
+#grid(columns: 3, radius: 4pt, stroke: 1pt, fill: aqua, ..cells)
+
+---
+// Error: 38-61 unexpected key "cake", valid keys are "top-left", "top-right", "bottom-right", "bottom-left", "left", "top", "right", "bottom", and "rest"
+#table(columns: 2, [a], [b], radius: (left: 10pt, cake: 5pt))
@@ -158,3 +158,34 @@ a
   [E], [F],
   [G], [H]
 )
+
+---
+// Ref: false
+// The debug overlay must not change the size of the grid.
+#let cells = ([A], [Longer], [B], [C])
+#context {
+  let plain = measure(grid(columns: (auto, 1fr), gutter: 3pt, ..cells))
+  let debug = measure(grid(columns: (auto, 1fr), gutter: 3pt, debug: true, ..cells))
+  test(plain, debug)
+}
+
+---
+// Error: 14-19 expected boolean, found string
+#grid(debug: "yes")
+
+---
+// Grid insets can be given per side, per axis, per column, and per cell.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#let size(inset) = measure(grid(columns: 2, inset: inset, cell, cell))
+#context {
+  test(size((left: 4pt, rest: 2pt)), (width: 2 * 16pt, height: 14pt))
+  test(size((x: 5pt, y: 1pt)), (width: 2 * 20pt, height: 12pt))
+  test(size((0pt, 5pt)), (width: 10pt + 20pt, height: 20pt))
+  test(size((x, y) => if x == 0 { (left: 6pt) } else { 0pt }), (width: 16pt + 10pt, height: 10pt))
+  test(size(((right: 3pt), (x: 2pt))), (width: 13pt + 14pt, height: 10pt))
+}
+
+---
+// Error: 14-19 expected relative length, dictionary, array, or function, found string
+#grid(inset: "big")
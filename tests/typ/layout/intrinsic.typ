@@ -0,0 +1,38 @@
+// Test measuring the intrinsic widths of content.
+
+---
+// Ref: false
+#context {
+  let sizes = intrinsic[Hello wonderful world]
+  test(sizes.min, measure[wonderful].width)
+  test(sizes.max, measure[Hello wonderful world].width)
+  assert(sizes.min < sizes.max)
+}
+
+---
+// Content that can't wrap has equal intrinsic widths.
+// Ref: false
+#context {
+  let (min, max) = intrinsic(box(width: 30pt))
+  test((min, max), (30pt, 30pt))
+  test(intrinsic(block(width: 30pt, height: 10pt)), (min: 30pt, max: 30pt))
+}
+
+---
+// Grids with fixed columns don't react to the probe for the minimum width.
+// Ref: false
+#context {
+  let cells = ([A], [B], [C])
+  let wide = grid(columns: (30pt,) * 3, ..cells)
+  test(intrinsic(wide), (min: 90pt, max: 90pt))
+  let shrink = grid(columns: (30pt,) * 3, overflow: "shrink", ..cells)
+  test(intrinsic(shrink), (min: 90pt, max: 90pt))
+  let error = grid(columns: (30pt,) * 3, overflow: "error", ..cells)
+  test(intrinsic(error), (min: 90pt, max: 90pt))
+}
+
+---
+// Error: 2-18 can only be used when context is known
+// Hint: 2-18 try wrapping this in a `context` expression
+// Hint: 2-18 the `context` expression should wrap everything that depends on this function
+#intrinsic[Hello]
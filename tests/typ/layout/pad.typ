@@ -28,3 +28,96 @@ Hi #box(pad(left: 10pt)[A]) there
 ---
 // Test that padding adding up to 100% does not panic.
 #pad(50%)[]
+
+---
+// Without reflow, padding shifts the content but keeps its size.
+// Ref: false
+#let body = block(width: 30pt, height: 20pt)[#metadata(none)<inner>]
+#context {
+  let plain = measure(body)
+  test(measure(pad(10pt, body)), (width: 50pt, height: 40pt))
+  test(measure(pad(10pt, reflow: false, body)), plain)
+  test(measure(pad(x: 50%, reflow: false, body)), plain)
+}
+
+#block[#metadata(none)<outer>#pad(left: 8pt, top: 4pt, reflow: false, body)]
+#context {
+  let outer = locate(<outer>).position()
+  let inner = locate(<inner>).position()
+  test((inner.x - outer.x, inner.y - outer.y), (8pt, 4pt))
+}
+
+---
+// Test that the top and bottom padding only apply at the edges of a pad
+// that breaks across pages.
+// Ref: false
+#set page(height: 100pt, margin: 0pt)
+#set block(spacing: 0pt)
+#pad(x: 5pt, top: 10pt, bottom: 20pt, split: true)[
+  #block(height: 150pt, breakable: true)[#metadata(none)<start>]
+  #metadata(none)<end>
+]
+#metadata(none)<after>
+
+#context {
+  let start = locate(<start>).position()
+  let end = locate(<end>).position()
+  let after = locate(<after>).position()
+  test((start.page, start.x, start.y), (1, 5pt, 10pt))
+  test((end.page, end.x, end.y), (2, 5pt, 60pt))
+  test((after.page, after.y), (2, 80pt))
+}
+
+---
+// Test that, by default, every piece of a broken pad is padded on all sides.
+// Ref: false
+#set page(height: 100pt, margin: 0pt)
+#set block(spacing: 0pt)
+#pad(x: 5pt, top: 10pt, bottom: 20pt)[
+  #block(height: 150pt, breakable: true)[#metadata(none)<start>]
+  #metadata(none)<end>
+]
+#metadata(none)<after>
+
+#context {
+  let start = locate(<start>).position()
+  let end = locate(<end>).position()
+  let after = locate(<after>).position()
+  test((start.page, start.x, start.y), (1, 5pt, 10pt))
+  test((end.page, end.x, end.y), (3, 5pt, 20pt))
+  test((after.page, after.y), (3, 40pt))
+}
+
+---
+// Test that relative sizes above 100% aren't clamped.
+// Ref: false
+#set page(width: 120pt, margin: 10pt)
+#block(width: 50pt)[
+  #box(width: 120%)[#h(1fr)#metadata(none)<wide>]
+  #pad(x: 60%)[#metadata(none)<padded>A]
+  #pad(x: 60% + 5pt)[#metadata(none)<mixed>A]
+]
+
+#context {
+  test(locate(<wide>).position().x, 70pt)
+  test(locate(<padded>).position().x, 10pt)
+  test(locate(<mixed>).position().x, 15pt)
+}
+
+---
+// Fractional padding distributes the free horizontal space.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#pad(left: 1fr, right: 2fr, box(width: 40pt)[#metadata(none)<third>])
+#pad(x: 1fr, box(width: 40pt)[#metadata(none)<half>])
+#pad(left: 10pt, right: 1fr, box(width: 40pt)[#metadata(none)<left>])
+#context {
+  test(locate(<third>).position().x, 20pt)
+  test(locate(<half>).position().x, 30pt)
+  test(locate(<left>).position().x, 10pt)
+  test(measure(pad(left: 1fr, box(width: 40pt))).width, 40pt)
+}
+
+---
+// Error: 11-14 expected relative length, found fraction
+#pad(top: 1fr)[A]
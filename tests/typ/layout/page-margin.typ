@@ -18,3 +18,17 @@
 
 // Ensure that specific margins override general margins.
 #[#set page(margin: (rest: 0pt, left: 20pt)); Overridden]
+
+---
+// Automatic margins centering a fixed content width.
+// Ref: false
+#set page(width: 100pt, height: 40pt, margin: (x: auto, y: 5pt), content-width: 60pt)
+#layout(size => test(size.width, 60pt))
+#context test(here().position().x, 20pt)
+
+---
+// Only the automatic side takes up the leftover space.
+// Ref: false
+#set page(width: 100pt, height: 40pt, margin: (left: 10pt, rest: auto), content-width: 60%)
+#layout(size => test(size.width, 60pt))
+#context test(here().position().x, 10pt)
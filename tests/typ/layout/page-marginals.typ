@@ -22,3 +22,49 @@ answer it.
 
 #set page(header: none, height: auto, margin: (top: 15pt, bottom: 25pt))
 The END.
+
+---
+// Test the size and position of the header and footer areas.
+// Ref: false
+#set page(
+  width: 100pt,
+  height: 100pt,
+  margin: (x: 20pt, top: 30pt, bottom: 10pt),
+  header: layout(size => [#metadata(size)<header>]),
+  footer: layout(size => [#metadata(size)<footer>]),
+)
+
+Body
+
+#context {
+  let header = query(<header>).first()
+  let footer = query(<footer>).first()
+  test(header.value, (width: 60pt, height: 21pt))
+  test(footer.value, (width: 60pt, height: 7pt))
+  test(header.location().position().x, 20pt)
+  test(footer.location().position().x, 20pt)
+}
+
+---
+// Test aligning the header towards the outside of the page.
+// Ref: false
+#set page(
+  width: 100pt,
+  height: 60pt,
+  margin: 10pt,
+  header: [#metadata(none)<head>Head],
+  header-align: "outside",
+  footer: [#metadata(none)<foot>Foot],
+  footer-align: "inside",
+)
+#pagebreak()
+
+#context {
+  let heads = query(<head>).map(it => it.location().position())
+  let feet = query(<foot>).map(it => it.location().position())
+  test(heads.map(it => it.page), (1, 2))
+  test(heads.at(0).x > 10pt, true)
+  test(heads.at(1).x, 10pt)
+  test(feet.at(0).x, 10pt)
+  test(feet.at(1).x > 10pt, true)
+}
@@ -40,3 +40,165 @@
   h(1em)
   place(left, rect(width: 80pt, stroke: blue))
 })
+
+---
+// Paper names are case-insensitive and have common aliases.
+// Ref: false
+#set page(paper: "Letter")
+#context test((page.width, page.height), (215.9mm, 279.4mm))
+
+#set page(paper: "JIS B5")
+#context test(page.width, 182mm)
+
+#set page(paper: "US_Legal")
+#context test(page.height, 355.6mm)
+
+---
+// Error: 18-23 unknown paper size "a12"; did you mean "a1"?
+#set page(paper: "a12")
+
+---
+// Error: 18-22 unknown paper size "a$"; did you mean "a4"?
+#set page(paper: "a$")
+
+---
+// Error: 18-28 unknown paper size "postcard"
+#set page(paper: "postcard")
+
+---
+// Test snapping the page's contents to a pixel grid.
+// Ref: false
+#set page(width: 100pt, height: 100pt, margin: 0pt, snap-to-pixel: true, dpi: 72)
+#place(dx: 10.4pt, dy: 20.6pt, block[#metadata(none)<snapped>])
+#place(dx: 10.4pt, dy: 20.6pt, rotate(10deg, block[#metadata(none)<rotated>]))
+#context {
+  let pos = locate(<snapped>).position()
+  test((pos.x, pos.y), (10pt, 21pt))
+}
+
+---
+// Error: 16-17 number must be positive
+#set page(dpi: 0)
+
+---
+// Test that the first page inset only applies to the first page.
+// Ref: false
+#set page(height: 100pt, margin: 10pt, first-page-inset: 30pt)
+#metadata(none)<first>
+#pagebreak()
+#metadata(none)<second>
+
+#context {
+  test(locate(<first>).position(), (page: 1, x: 10pt, y: 40pt))
+  test(locate(<second>).position(), (page: 2, x: 10pt, y: 10pt))
+}
+
+---
+// Test that an automatically sized page fits its content plus the margins.
+// Ref: false
+#set page(
+  width: auto,
+  height: auto,
+  margin: (x: 5pt, y: 3pt),
+  background: layout(size => test(size, (width: 30pt, height: 16pt))),
+)
+#rect(width: 20pt, height: 10pt)
+
+---
+// Test that first page overrides apply only to the very first page.
+// Ref: false
+#set page(
+  height: 100pt,
+  margin: 20pt,
+  header: [#metadata(none)<header>],
+  first: (header: none, margin: (top: 10pt)),
+)
+#metadata(none)<title>Title
+#pagebreak()
+#metadata(none)<content>Content
+
+#context {
+  let header = query(<header>)
+  test(header.len(), 1)
+  test(header.first().location().page(), 2)
+  test(locate(<title>).position().y, 10pt)
+  test(locate(<content>).position().y, 20pt)
+}
+
+---
+// Test that repeating the set rule applies the same overrides again.
+// Ref: false
+#let chapter(body) = {
+  set page(first: (header: none))
+  body
+}
+#set page(height: 100pt, header: [#metadata(none)<header>])
+#chapter[One #pagebreak() Two]
+#chapter[Three]
+#set page(fill: none)
+Four
+
+#context test(query(<header>).map(it => it.location().page()), (2, 4))
+
+---
+// Error: 18-31 the first page can only override the top and bottom margins
+#set page(first: (margin: 5pt))
+
+---
+// Test aligning the page's content as a whole.
+// Ref: false
+#set page(height: 100pt, margin: 10pt, align: bottom)
+#block(height: 30pt)[#metadata(none) <top>]
+#context test(locate(<top>).position().y, 60pt)
+
+---
+// Test centering the page's content on both axes.
+// Ref: false
+#set page(width: 100pt, height: 100pt, margin: 10pt, align: center + horizon)
+#block(width: 20pt, height: 20pt)[#metadata(none) <corner>]
+#context {
+  let pos = locate(<corner>).position()
+  test((pos.x, pos.y), (40pt, 40pt))
+}
+
+---
+// A `none` margin resets a previously set margin to the default.
+// Ref: false
+#set page(width: 100pt, height: 200pt, margin: 0pt)
+#set page(margin: (left: none))
+#metadata(none)<start>
+#context {
+  let pos = locate(<start>).position()
+  let default = 100pt * 2.5 / 21
+  assert(calc.abs((pos.x - default).pt()) < 0.01)
+  test(pos.y, 0pt)
+}
+
+---
+// A `none` margin resets all sides, while `0pt` removes them.
+// Ref: false
+#set page(width: 100pt, height: 200pt, margin: 5pt)
+#set page(margin: none)
+#metadata(none)<start>
+#pagebreak()
+#set page(margin: 0pt)
+#metadata(none)<zero>
+#context {
+  let pos = locate(<start>).position()
+  let default = 100pt * 2.5 / 21
+  assert(calc.abs((pos.x - default).pt()) < 0.01)
+  assert(calc.abs((pos.y - default).pt()) < 0.01)
+  let zero = locate(<zero>).position()
+  test((zero.x, zero.y), (0pt, 0pt))
+}
+
+---
+// Test that a first page inset taller than the content area is clamped.
+// Ref: false
+#set page(height: 100pt, margin: 10pt, first-page-inset: 200pt)
+#block(height: 20pt)[#metadata(none)<moved>]
+
+#context {
+  test(counter(page).final(), (2,))
+  test(locate(<moved>).position(), (page: 2, x: 10pt, y: 10pt))
+}
@@ -43,3 +43,73 @@ First
 #page[Second]
 #pagebreak(weak: true)
 #page[Third]
+
+---
+// A pagebreak inside of a breakable block continues the block on the next
+// page.
+// Ref: false
+#set page(height: 100pt)
+#block(stroke: 1pt)[
+  #metadata(none)<before> Before
+  #pagebreak()
+  #metadata(none)<after> After
+]
+#context {
+  test(locate(<before>).page(), 1)
+  test(locate(<after>).page(), 2)
+  test(counter(page).final(), (2,))
+}
+
+---
+// A weak pagebreak at the start of a block is ignored.
+// Ref: false
+#block[
+  #pagebreak(weak: true)
+  #metadata(none)<first> First
+]
+#context test(locate(<first>).page(), 1)
+
+---
+#block(breakable: false)[
+  // Error: 4-15 pagebreaks are not allowed inside of containers
+  // Hint: 4-15 only breakable blocks can contain pagebreaks
+  #pagebreak()
+]
+
+---
+// Test joining paragraph and page breaks with other content.
+// Ref: false
+#set page(height: 100pt)
+#let part(name) = [#metadata(name)<part>#name]
+#let doc = part("a") + parbreak() + part("b") + pagebreak() + part("c")
+#test(doc.func(), [].func())
+#doc
+
+#context {
+  let (a, b, c) = query(<part>).map(it => it.location().position())
+  test((a.page, b.page, c.page), (1, 1, 2))
+  test(a.x, b.x)
+  assert(b.y > a.y)
+}
+
+---
+// A pagebreak inside of a block in columns skips the remaining columns of
+// the page.
+// Ref: false
+#set page(height: 100pt, columns: 2)
+#block[
+  #metadata(none)<before> Before
+  #pagebreak()
+  #metadata(none)<after> After
+]
+#context {
+  test(locate(<after>).page(), 2)
+  test(locate(<after>).position().x, locate(<before>).position().x)
+}
+
+---
+#block[
+  // Error: 4-23 pagebreaks to an even or odd page are not allowed inside of containers
+  // Hint: 4-23 try moving the pagebreak out of the container
+  #pagebreak(to: "odd")
+]
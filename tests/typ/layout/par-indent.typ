@@ -49,3 +49,47 @@ Welcome \ here. Does this work well?
 #set text(dir: rtl)
 لآن وقد أظلم الليل وبدأت النجوم
 تنضخ وجه الطبيعة التي أعْيَتْ من طول ما انبعثت في النهار
+
+---
+// Test the line positions with a hanging indent.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#set par(hanging-indent: 10pt)
+#metadata(none) First \
+#metadata(none) second
+#context test(query(metadata).map(it => it.location().position().x), (0pt, 10pt))
+
+---
+// A negative hanging indent indents the first line instead.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#set par(hanging-indent: -10pt)
+#metadata(none) First \
+#metadata(none) second
+#context test(query(metadata).map(it => it.location().position().x), (10pt, 0pt))
+
+---
+// Test indenting all paragraphs, including the first one after a heading.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#set par(first-line-indent: (amount: 8pt, all: true))
+= Heading
+#metadata(none) First
+
+#metadata(none) Second
+#context test(query(metadata).map(it => it.location().position().x), (8pt, 8pt))
+
+---
+// Without `all`, the first paragraph after a heading is not indented.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#set par(first-line-indent: 8pt)
+= Heading
+#metadata(none) First
+
+#metadata(none) Second
+#context test(query(metadata).map(it => it.location().position().x), (0pt, 8pt))
+
+---
+// Error: 29-53 unexpected key "size", valid keys are "amount" and "all"
+#set par(first-line-indent: (amount: 1em, size: 2pt))
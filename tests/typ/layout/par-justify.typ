@@ -63,3 +63,26 @@ int main() {
 }
 ```
 
+
+---
+// Test alignment of the last line of a justified paragraph.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#set par(justify: true, last-line: right)
+#metadata(none)<right>Hi
+
+#set par(last-line: "justify")
+A #metadata(none)<justified>B
+
+#set par(justify: false)
+#metadata(none)<ragged>Hi
+
+#context {
+  test(locate(<right>).position().x, 100pt - measure[Hi].width)
+  test(locate(<justified>).position().x, 100pt - measure[B].width)
+  test(locate(<ragged>).position().x, 0pt)
+}
+
+---
+// Error: 21-27 expected "justify", alignment, or auto
+#set par(last-line: "left")
@@ -0,0 +1,47 @@
+// Test tab stops.
+
+---
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#set par(tabs: (50pt, (120pt, end)))
+A #tab #metadata(none) <a> B #tab #box(width: 10pt) #metadata(none) <b>
+
+#context {
+  test(locate(<a>).position().x, 50pt)
+  test(locate(<b>).position().x, 120pt)
+}
+
+---
+// A marker advances to the first stop past the preceding content.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#set par(tabs: (5pt, 60pt))
+#box(width: 20pt) #tab #metadata(none) <a>
+
+#context test(locate(<a>).position().x, 60pt)
+
+---
+// Decimal alignment.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#set par(tabs: ((100pt, "decimal"),))
+#tab 1#metadata(none) <a>.25 \
+#tab 1250#metadata(none) <b>.5
+
+#context {
+  test(locate(<a>).position().x, 100pt)
+  test(locate(<b>).position().x, 100pt)
+}
+
+---
+// Ref: false
+#set par(tabs: (1cm, (2cm, center), (3cm, right), (4cm, "decimal")))
+#context test(par.tabs, (1cm, (2cm, center), (3cm, end), (4cm, "decimal")))
+
+---
+// Error: 16-34 tab stop array must contain exactly two entries
+#set par(tabs: ((1cm, 2cm, 3cm),))
+
+---
+// Error: 16-29 expected "decimal" or alignment, found length
+#set par(tabs: ((1cm, 2cm),))
@@ -13,6 +13,43 @@ But, soft! what light through yonder window breaks?
 
 It is the east, and Juliet is the sun.
 
+---
+// Leading resolves em relative to the font size and is scoped like any
+// other property.
+// Ref: false
+#set text(size: 10pt)
+#let lines = [A \ B]
+#context {
+  let base = measure(block[#set par(leading: 0pt); #lines]).height
+  test(measure(block[#set par(leading: 5pt); #lines]).height - base, 5pt)
+  test(measure(block[#set par(leading: 1.5em); #lines]).height - base, 15pt)
+  test(
+    measure(block[#set text(size: 20pt); #set par(leading: 1em); #lines]).height
+      - measure(block[#set text(size: 20pt); #set par(leading: 0pt); #lines]).height,
+    20pt,
+  )
+  test(measure(block[#[#set par(leading: 2em)]#lines]), measure(block(lines)))
+}
+
+---
+// Paragraph spacing is independent of leading and collapses with the spacing
+// of adjacent blocks.
+// Ref: false
+#set text(size: 10pt)
+#let rule = block(height: 5pt, spacing: 4pt)
+#context {
+  let line = measure(block[A]).height
+  let height(spacing, ..children) = measure(block({
+    set par(spacing: spacing)
+    children.pos().join(parbreak())
+  })).height
+  test(height(8pt, [A], [B]), 2 * line + 8pt)
+  test(height(auto, [A], [B]), 2 * line + 1.2em.to-absolute())
+  test(height(10pt, [A], rule, [B]), 2 * line + 5pt + 20pt)
+  test(height(2pt, [A], rule, [B]), 2 * line + 5pt + 8pt)
+  test(height(10pt, [A], block(height: 5pt, above: 1pt, below: 1pt), [B]), 2 * line + 7pt)
+}
+
 ---
 // Test that paragraph spacing loses against block spacing.
 // TODO
@@ -33,3 +33,27 @@ First
 #place(bottom + right)[Placed]
 
 Second
+
+---
+// Test negative offsets from each corner.
+// Ref: false
+#set page(width: 100pt, height: 100pt, margin: 20pt)
+#let probe(name) = block(width: 10pt, height: 10pt)[#metadata(name)<probe>]
+#place(top + left, dx: -5pt, dy: -5pt, probe("top-left"))
+#place(top + right, dx: 5pt, dy: -5pt, probe("top-right"))
+#place(bottom + left, dx: -5pt, dy: 5pt, probe("bottom-left"))
+#place(bottom + right, dx: 5pt, dy: 5pt, clip: true, probe("bottom-right"))
+#place(top + left, dx: -30pt, dy: -30pt, clip: true, probe("outside"))
+
+#context {
+  let pos = (:)
+  for it in query(<probe>) {
+    let p = it.location().position()
+    pos.insert(it.value, (p.x, p.y))
+  }
+  test(pos.at("top-left"), (15pt, 15pt))
+  test(pos.at("top-right"), (75pt, 15pt))
+  test(pos.at("bottom-left"), (15pt, 75pt))
+  test(pos.at("bottom-right"), (75pt, 75pt))
+  test(pos.at("outside"), (-10pt, -10pt))
+}
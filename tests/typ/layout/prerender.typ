@@ -0,0 +1,24 @@
+// Test laying out content ahead of time.
+
+---
+// Ref: false
+#context {
+  let tile = prerender(rect(width: 20pt, height: 10pt))
+  test(tile.func(), box)
+  test(measure(tile), (width: 20pt, height: 10pt))
+  test(measure(stack(dir: ltr, tile, tile)).width, 40pt)
+}
+
+---
+// Styles applied around the tile don't affect it anymore.
+// Ref: false
+#context {
+  let tile = prerender[Hello]
+  test(measure(text(size: 30pt, tile)), measure[Hello])
+}
+
+---
+// Error: 2-19 can only be used when context is known
+// Hint: 2-19 try wrapping this in a `context` expression
+// Hint: 2-19 the `context` expression should wrap everything that depends on this function
+#prerender[Hello]
@@ -0,0 +1,35 @@
+// Test which base relative lengths are resolved against.
+
+---
+// All layout functions resolve relative lengths against the size of the
+// surrounding container.
+// Ref: false
+#set page(width: 200pt, height: 100pt, margin: 0pt)
+#let len = 50% - 10pt
+
+#box(width: len, height: 10pt)#metadata(none)<box>
+
+#h(len)#metadata(none)<h>
+
+#metadata(none)<v-before>
+#v(len)
+#metadata(none)<v-after>
+
+#grid(
+  columns: (len, auto),
+  rows: (len, auto),
+  [], [#metadata(none)<column>],
+  [#metadata(none)<row>],
+)
+
+#pad(left: len)[#block[#metadata(none)<pad>]]
+
+#context {
+  let pos(label) = locate(label).position()
+  test(pos(<box>).x, 90pt)
+  test(pos(<h>).x, 90pt)
+  test(pos(<v-after>).y - pos(<v-before>).y, 40pt)
+  test(pos(<column>).x, 90pt)
+  test(pos(<row>).y - pos(<column>).y, 40pt)
+  test(pos(<pad>).x, 90pt)
+}
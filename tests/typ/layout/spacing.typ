@@ -44,3 +44,61 @@ A #h(1fr) B
 // Missing spacing.
 // Error: 10-13 missing argument: amount
 Totally #h() ignored
+
+---
+// Auto spacing behaves like `1fr`.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#set text(size: 10pt)
+#grid(
+  rows: 40pt,
+  columns: 2,
+  [#v(auto)#metadata("auto")<bottom>],
+  [#v(1fr)#metadata("fr")<bottom>],
+)
+A#h(auto)#metadata("h-auto")<right>B#h(auto)C \
+A#h(1fr)#metadata("h-fr")<right>B#h(1fr)C
+#context {
+  let ys = query(<bottom>).map(it => it.location().position().y)
+  test(ys.at(0), ys.at(1))
+  let xs = query(<right>).map(it => it.location().position().x)
+  test(xs.at(0), xs.at(1))
+}
+
+---
+// Error: 4-10 expected relative length, fraction, or auto, found string
+#v("big")
+
+---
+// Spacing in `em` resolves against the text size where it is inserted.
+// Ref: false
+#set page(width: 200pt, margin: 0pt)
+#set par(leading: 0pt, spacing: 0pt)
+#set text(size: 10pt, top-edge: "ascender", bottom-edge: "descender")
+#metadata(none)<a>A
+#v(2em)
+#metadata(none)<b>B
+
+#set text(size: 20pt)
+#metadata(none)<c>C
+#v(2em)
+#metadata(none)<d>D
+
+#context {
+  let y(label) = locate(label).position().y
+  test(y(<b>) - y(<a>), measure(text(10pt)[A]).height + 20pt)
+  test(y(<d>) - y(<c>), measure(text(20pt)[C]).height + 40pt)
+}
+
+---
+// Horizontal spacing in `em` resolves against the local text size.
+// Ref: false
+#set text(size: 10pt)
+#let x(label) = locate(label).position().x
+A#metadata(none)<h1>#h(2em)#metadata(none)<h2>
+#text(20pt)[A#metadata(none)<h3>#h(2em)#metadata(none)<h4>]
+#set text(size: 5pt)
+#context {
+  test(x(<h2>) - x(<h1>), 20pt)
+  test(x(<h4>) - x(<h3>), 40pt)
+}
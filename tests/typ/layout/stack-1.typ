@@ -50,3 +50,75 @@
   align(left, [B]),
   [C],
 )
+
+---
+// Test justification of stack items.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#let item(i) = box(width: 10pt)[#metadata(i) <item>]
+#stack(dir: ltr, justify: "space-between", ..range(3).map(item))
+#context test(
+  query(<item>).map(it => it.location().position().x),
+  (0pt, 45pt, 90pt),
+)
+
+---
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#let item(i) = box(width: 10pt)[#metadata(i) <item>]
+#stack(dir: rtl, justify: "space-evenly", ..range(3).map(item))
+#context test(
+  query(<item>).map(it => it.location().position().x),
+  (72.5pt, 45pt, 17.5pt),
+)
+
+---
+// Justification has no effect without a fixed size along the axis.
+// Ref: false
+#let r = rect(width: 10pt)
+#context test(
+  measure(stack(dir: ltr, justify: "space-around", r, r)).width,
+  20pt,
+)
+
+---
+// Error: 17-26 expected "start", "space-between", "space-around", or "space-evenly"
+#stack(justify: "between")
+
+---
+// Test that array children come after the positional ones.
+// Ref: false
+#let marked(i) = [#metadata(i)<item>#i]
+#stack(dir: ltr, spacing: 5pt, marked(0), items: range(1, 4).map(marked))
+
+#context {
+  let items = query(<item>)
+  test(items.map(it => it.value), (0, 1, 2, 3))
+  let xs = items.map(it => it.location().position().x)
+  test(xs, xs.sorted())
+  test(xs.dedup().len(), 4)
+}
+
+---
+// Error: 15-16 expected array, found integer
+#stack(items: 5)
+
+---
+// Test that computed directions are accepted.
+// Ref: false
+#let item = box(width: 10pt, height: 20pt)
+#let size(horizontal) = {
+  let dir = if horizontal { ltr } else { ttb }
+  let size = measure(stack(dir: dir, item, item))
+  (size.width, size.height)
+}
+#context {
+  test(size(true), (20pt, 20pt))
+  test(size(false), (10pt, 40pt))
+  test(measure(stack(dir: ltr.inv(), item, item)).width, 20pt)
+  test(stack(dir: ttb.inv()).dir, btt)
+}
+
+---
+// Error: 13-24 expected direction, found alignment
+#stack(dir: ltr.start())
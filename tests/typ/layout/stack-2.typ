@@ -21,3 +21,153 @@ World! 🌍
           #v(1fr)
   #h(1fr) Hi you!
 ]
+
+---
+// Fractional spacing spreads the items across the full width.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#let probe(name) = box(width: 10pt, height: 10pt, place(metadata(name)))
+#stack(dir: ltr, spacing: 1fr, probe("a"), probe("b"), probe("c"))
+#context {
+  let xs = query(metadata).map(it => it.location().position().x)
+  test(xs, (0pt, 45pt, 90pt))
+}
+
+---
+// Stack items can be located through labelled metadata, e.g. to connect them.
+// Ref: false
+#set page(width: 200pt, height: auto, margin: 0pt)
+#let node(name, width) = block(width: width, height: 10pt)[#metadata(name)<node>]
+#stack(
+  dir: ttb,
+  spacing: 5pt,
+  node("a", 20pt),
+  node("b", 40pt),
+  node("c", 20pt),
+)
+#context {
+  let positions = query(<node>).map(it => it.location().position())
+  test(positions.map(p => p.x), (0pt, 0pt, 0pt))
+  test(positions.map(p => p.y), (0pt, 15pt, 30pt))
+  let (a, b, ..) = positions
+  place(line(start: (a.x + 10pt, a.y + 10pt), end: (b.x + 20pt, b.y)))
+}
+
+---
+// Test the stack's cross-axis alignment.
+// Ref: false
+#set page(width: 100pt, height: auto, margin: 0pt)
+#stack(
+  align: center,
+  rect(width: 40pt),
+  block(width: 20pt)[#metadata(none)<centered>],
+  align(right, block(width: 20pt)[#metadata(none)<right>]),
+)
+
+#context test(locate(<centered>).position().x, 10pt)
+#context test(locate(<right>).position().x, 20pt)
+
+---
+// Test that a horizontal stack aligns vertically.
+// Ref: false
+#set page(width: 100pt, height: auto, margin: 0pt)
+#stack(
+  dir: ltr,
+  align: bottom,
+  rect(height: 40pt),
+  block(height: 10pt)[#metadata(none)<bottom>],
+)
+
+#context test(locate(<bottom>).position().y, 30pt)
+
+---
+// Error: 2-24 expected horizontal alignment for vertical stack
+// Hint: 2-24 the alignment applies along the stack's cross axis
+#stack(align: top, [A])
+
+---
+// Test that empty children don't get spacing around them.
+// Ref: false
+#set page(width: 100pt, height: auto, margin: 0pt)
+#let probe(name) = block(height: 10pt)[#metadata(name)<stack-probe>]
+#stack(spacing: 5pt, [], probe("a"), [], [#metadata(none)], probe("b"), [])
+
+#context {
+  let ys = query(<stack-probe>).map(it => it.location().position().y)
+  test(ys, (0pt, 15pt))
+  test(measure(stack(spacing: 5pt, probe("a"), [], probe("b"))).height, 25pt)
+}
+
+---
+// `gap` is an alias for `spacing`.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#context {
+  test(measure(stack(gap: 5pt, cell, cell)).height, 25pt)
+  test(measure(stack(gap: 5pt, spacing: 2pt, cell, cell)).height, 22pt)
+}
+
+#set stack(gap: 3pt)
+#context test(measure(stack(cell, cell)).height, 23pt)
+
+---
+// Test aligning the items of a horizontal stack on their first baseline.
+// Ref: false
+#context {
+  let big = text(20pt)[A]
+  let small = box(inset: (bottom: 10pt))[A]
+  let stack = stack(dir: ltr, align: "baseline", big, small)
+  test(measure(stack).height, measure(big).height + 10pt)
+}
+
+---
+// Error: 2-36 baseline alignment is only supported for horizontal stacks
+#stack(align: "baseline", [A], [B])
+
+---
+// Test justification in a right-to-left stack.
+// Ref: false
+#set page(width: 100pt, margin: 0pt)
+#let item(name) = box(width: 20pt)[#metadata(name)<item>]
+#stack(dir: rtl, justify: "space-between", item(0), item(1), item(2))
+
+#context {
+  let xs = query(<item>).map(it => it.location().position().x)
+  test(xs, (80pt, 40pt, 0pt))
+}
+
+---
+// Test stretching the items of a horizontal stack to the tallest one.
+// Ref: false
+#let probe = box(
+  width: 5pt,
+  height: 100%,
+  layout(size => [#metadata(size.height)<stretched>]),
+)
+
+#stack(dir: ltr, stretch: true, box(width: 10pt, height: 20pt), probe)
+#context {
+  test(query(<stretched>).first().value, 20pt)
+  let stack = stack(dir: ltr, stretch: true, rect(height: 15pt), rect(height: 100%))
+  test(measure(stack).height, 15pt)
+}
+
+---
+// Test collapsing consecutive spacings in a stack.
+// Ref: false
+#let cell = box(width: 10pt, height: 10pt)
+#context {
+  test(measure(stack(cell, v(8pt), 4pt, cell)).height, 32pt)
+  test(measure(stack(collapse: "max", cell, v(8pt), 4pt, cell)).height, 28pt)
+  test(measure(stack(collapse: "max", cell, 5pt, cell, 2pt, 1em, cell)).height, 35pt + text.size)
+  test(measure(stack(dir: ltr, collapse: "max", cell, h(3pt), 6pt, cell)).width, 26pt)
+}
+
+#set page(height: 100pt, margin: 0pt)
+#set stack(collapse: "max")
+#stack(cell, 20pt, 1fr, [#metadata(none)<last>A])
+#context test(locate(<last>).position().y, 100pt - measure[A].height)
+
+---
+// Error: 28-34 expected "sum" or "max"
+#stack(dir: ltr, collapse: "none")
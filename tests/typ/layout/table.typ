@@ -139,3 +139,53 @@
 ---
 // Error: 14-19 expected color, gradient, pattern, none, array, or function, found string
 #table(fill: "hey")
+
+---
+// Test decimal alignment.
+// Ref: false
+#table(
+  columns: (auto, 60pt),
+  decimal: (x, y) => x == 1,
+  [A], [1#metadata(none)<sep>.5],
+  [B], [120#metadata(none)<sep>.25],
+  [C], [7#metadata(none)<sep>],
+  table.cell(colspan: 2)[Ignored.],
+)
+
+#context {
+  let xs = query(<sep>).map(it => it.location().position().x)
+  test(xs.dedup().len(), 1)
+}
+
+---
+// Auto columns grow to fit the shifted cells.
+// Ref: false
+#set table(inset: 0pt, stroke: none)
+#context {
+  let width = measure(table(decimal: true, [1.555], [100.2])).width
+  let expected = measure[100].width + measure[.555].width
+  assert(calc.abs((width - expected).pt()) < 0.01)
+}
+
+---
+// Fractional columns only take the space left by grown auto columns.
+// Ref: false
+#set page(width: 120pt, margin: 10pt)
+#set table(inset: 0pt, stroke: none)
+#table(
+  columns: (auto, 1fr),
+  decimal: (x, y) => x == 0,
+  [1.555], [#metadata(none)<start>],
+  [100.2], [#h(1fr)#metadata(none)<end>],
+)
+
+#context {
+  let expected = 10pt + measure[100].width + measure[.555].width
+  let start = locate(<start>).position().x
+  assert(calc.abs((start - expected).pt()) < 0.01)
+  test(locate(<end>).position().x, 110pt)
+}
+
+---
+// Error: 17-22 expected boolean, array, or function, found string
+#table(decimal: "yes")
@@ -56,3 +56,18 @@ Hello #scaled[World]!\
 
 #set scale(reflow: true)
 Hello #scaled[World]!
+
+---
+// Test that a reflowed rotation sizes the grid cell it is placed in.
+// Ref: false
+#set grid(inset: 0pt)
+#context {
+  let header = [Header]
+  let size = measure(header)
+  let rotated = rotate(-90deg, reflow: true, header)
+  test(measure(rotated).width, size.height)
+  test(measure(rotated).height, size.width)
+  let table = grid(columns: 2, rotated, [A])
+  test(measure(table).height, size.width)
+  test(measure(grid(rotated)).width, size.height)
+}
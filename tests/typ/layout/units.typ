@@ -0,0 +1,32 @@
+// Test lengths that combine multiple units in layout arguments.
+
+---
+// Ref: false
+#let approx(a, b) = assert(calc.abs((a - b).pt()) < 0.001, message: repr((a, b)))
+#set text(size: 10pt)
+#set page(
+  width: 2cm + 1in - 1em,
+  height: auto,
+  margin: (left: 5mm + 1em, rest: 0pt),
+  background: layout(size => approx(size.width, 2cm + 72pt - 10pt)),
+)
+
+#context {
+  approx(measure(box(width: 1cm + 2mm + 1em)).width, 12mm + 10pt)
+  approx(measure(box(height: 0.5in - 2em)).height, 36pt - 20pt)
+  approx(measure(pad(x: 1mm + 1em, box(width: 1cm))).width, 1cm + 2mm + 20pt)
+  approx(measure(pad(y: 1in - 1em)[]).height, 2 * (72pt - 10pt))
+  approx(measure(stack(dir: ltr, box(), h(1cm + 1em), box())).width, 1cm + 10pt)
+  approx(measure(stack(box(), v(2mm - 0.5em), box())).height, 2mm - 5pt)
+  approx(
+    measure(grid(columns: (1cm + 1em, 1in - 2em), [], [])).width,
+    1cm + 72pt - 10pt,
+  )
+  approx(measure(grid(columns: 2, gutter: 1mm + 1em, [], [])).width, 1mm + 10pt)
+}
+
+#set text(size: 20pt)
+#context approx(measure(box(width: 1cm + 1em)).width, 1cm + 20pt)
+
+#[#metadata(none)<start>]
+#context approx(locate(<start>).position().x, 5mm + 10pt)
@@ -8,3 +8,12 @@
 #set page(numbering: "1 / 1")
 #counter(page).update(1)
 #lorem(20)
+
+---
+// Test the total page count.
+// Ref: false
+#set page(height: 50pt, margin: 10pt)
+#context test(counter(page).final(), (3,))
+#pagebreak()
+#pagebreak()
+#context test(counter(page).get(), (3,))
@@ -45,5 +45,6 @@ Hello
 ---
 #box[
   // Error: 4-15 pagebreaks are not allowed inside of containers
+  // Hint: 4-15 only breakable blocks can contain pagebreaks
   #pagebreak()
 ]
@@ -109,3 +109,27 @@ We can clearly see that @fig-cylinder and
   table(columns: 2)[a][b],
   caption: [The table with custom separator.],
 )
+
+---
+// Test that tables in figures are numbered sequentially and that the
+// numbering can be reset per chapter.
+// Ref: false
+#show heading.where(level: 1): it => {
+  counter(figure.where(kind: table)).update(0)
+  it
+}
+
+= First
+#figure(table[a], caption: [One]) <one>
+#figure(image("/assets/images/tiger.jpg", width: 1cm), caption: [Tiger])
+#figure(table[b], caption: [Two]) <two>
+
+= Second
+#figure(table[c], caption: [Three]) <three>
+
+#context {
+  let tables = counter(figure.where(kind: table))
+  test(tables.at(<one>), (1,))
+  test(tables.at(<two>), (2,))
+  test(tables.at(<three>), (1,))
+}
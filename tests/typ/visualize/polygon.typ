@@ -34,3 +34,11 @@
 ---
 // Error: 10-17 point array must contain exactly two entries
 #polygon((50pt,))
+
+---
+// Test that a polygon is sized to the extent of its vertices.
+// Ref: false
+#context {
+  let size = measure(polygon((5pt, 0pt), (20pt, 8pt), (0pt, 12pt)))
+  test(size, (width: 20pt, height: 12pt))
+}